@@ -0,0 +1,177 @@
+use crate::math::Vector2;
+use crate::rules::{mass_to_radius, Rules};
+use crate::world::{Circle, Entity};
+
+// How far past the edges of two circles a bot notices the other one.
+pub const BOT_SIGHT_RANGE: f32 = 60.0;
+
+// Where a bot wants to go, in the same form as `update_player_input`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Steering {
+    pub direction: Vector2,
+    pub speed: f32,
+}
+
+fn edge_distance(a: &Entity, b: &Entity) -> f32 {
+    (a.position - b.position).magnitude() - mass_to_radius(a.mass) - mass_to_radius(b.mass)
+}
+
+fn heading(from: Vector2, to: Vector2) -> Option<Vector2> {
+    let offset = to - from;
+    if offset.sqr_magnitude() == 0.0 {
+        None
+    } else {
+        Some(offset.normalized())
+    }
+}
+
+// Steers the bot `player_id` from the point of view of its largest circle:
+// flee the nearest circle in sight that could eat it, otherwise chase the
+// nearest circle in sight it could eat, otherwise head for the nearest food.
+// Returns `None` if the bot has no circles or nothing to go after.
+pub fn steer_bot(
+    rules: &Rules,
+    player_id: u32,
+    circles: &[Circle],
+    food: &[Entity],
+) -> Option<Steering> {
+    let me = circles
+        .iter()
+        .filter(|circle| circle.player_id == player_id)
+        .max_by_key(|circle| circle.entity.mass)?
+        .entity;
+
+    let nearest_in_sight = |can_eat: &dyn Fn(&Entity) -> bool| {
+        circles
+            .iter()
+            .filter(|other| other.player_id != player_id && can_eat(&other.entity))
+            .map(|other| (edge_distance(&me, &other.entity), other.entity.position))
+            .filter(|(distance, _)| *distance <= BOT_SIGHT_RANGE)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, position)| position)
+    };
+
+    if let Some(threat) = nearest_in_sight(&|other| rules.can_eat(other, &me)) {
+        // Run straight away, or pick any way out if we are exactly on top of it
+        let direction = heading(threat, me.position).unwrap_or(Vector2::new(1.0, 0.0));
+        return Some(Steering {
+            direction,
+            speed: 1.0,
+        });
+    }
+
+    let target = nearest_in_sight(&|other| rules.can_eat(&me, other)).or_else(|| {
+        food.iter()
+            .min_by(|a, b| {
+                let da = (a.position - me.position).sqr_magnitude();
+                let db = (b.position - me.position).sqr_magnitude();
+                da.total_cmp(&db)
+            })
+            .map(|food| food.position)
+    })?;
+    Some(Steering {
+        direction: heading(me.position, target)?,
+        speed: 1.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::START_PLAYER_MASS;
+
+    const BOT: u32 = 1;
+    const OTHER: u32 = 2;
+
+    fn entity(entity_id: u32, x: f32, y: f32, mass: u32) -> Entity {
+        Entity {
+            entity_id,
+            position: Vector2::new(x, y),
+            mass,
+        }
+    }
+
+    fn circle(entity_id: u32, player_id: u32, x: f32, y: f32, mass: u32) -> Circle {
+        Circle {
+            entity: entity(entity_id, x, y, mass),
+            player_id,
+            direction: Vector2::new(0.0, 1.0),
+            speed: 0.0,
+            last_split_micros: 0,
+            impulse: Vector2::default(),
+        }
+    }
+
+    fn steer(circles: &[Circle], food: &[Entity]) -> Option<Steering> {
+        steer_bot(&Rules::default(), BOT, circles, food)
+    }
+
+    #[test]
+    fn seeks_nearest_food() {
+        let circles = [circle(1, BOT, 500.0, 500.0, START_PLAYER_MASS)];
+        let food = [entity(2, 500.0, 300.0, 3), entity(3, 600.0, 500.0, 3)];
+
+        let steering = steer(&circles, &food).unwrap();
+
+        assert_eq!(steering.direction, Vector2::new(1.0, 0.0));
+        assert_eq!(steering.speed, 1.0);
+    }
+
+    #[test]
+    fn flees_larger_circle_in_sight() {
+        let circles = [
+            circle(1, BOT, 500.0, 500.0, START_PLAYER_MASS),
+            circle(2, OTHER, 500.0, 520.0, START_PLAYER_MASS * 4),
+        ];
+        let food = [entity(3, 500.0, 600.0, 3)];
+
+        let steering = steer(&circles, &food).unwrap();
+
+        assert_eq!(steering.direction, Vector2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn chases_smaller_circle_over_food() {
+        let circles = [
+            circle(1, BOT, 500.0, 500.0, START_PLAYER_MASS * 4),
+            circle(2, OTHER, 540.0, 500.0, START_PLAYER_MASS),
+        ];
+        let food = [entity(3, 500.0, 490.0, 3)];
+
+        let steering = steer(&circles, &food).unwrap();
+
+        assert_eq!(steering.direction, Vector2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn ignores_circles_out_of_sight() {
+        let circles = [
+            circle(1, BOT, 100.0, 100.0, START_PLAYER_MASS),
+            circle(2, OTHER, 900.0, 900.0, START_PLAYER_MASS * 4),
+        ];
+        let food = [entity(3, 100.0, 150.0, 3)];
+
+        let steering = steer(&circles, &food).unwrap();
+
+        assert_eq!(steering.direction, Vector2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn ignores_own_circles() {
+        let circles = [
+            circle(1, BOT, 500.0, 500.0, START_PLAYER_MASS),
+            circle(2, BOT, 510.0, 500.0, START_PLAYER_MASS * 4),
+        ];
+
+        // The largest circle looks around, and its sibling is neither threat nor prey
+        assert_eq!(steer(&circles, &[]), None);
+    }
+
+    #[test]
+    fn no_steering_without_circles() {
+        let circles = [circle(1, OTHER, 500.0, 500.0, START_PLAYER_MASS)];
+        let food = [entity(2, 500.0, 600.0, 3)];
+
+        assert_eq!(steer(&circles, &food), None);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::math::Vector2;
+use std::collections::HashMap;
+
+// A uniform grid used as the collision broadphase of `World::step`.
+// Every entity is registered in all the cells covered by its bounding box,
+// so a query only has to look at the cells covered by the querying circle.
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<u32>>,
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell_range(&self, position: Vector2, radius: f32) -> ((i32, i32), (i32, i32)) {
+        let min = (
+            ((position.x - radius) / self.cell_size).floor() as i32,
+            ((position.y - radius) / self.cell_size).floor() as i32,
+        );
+        let max = (
+            ((position.x + radius) / self.cell_size).floor() as i32,
+            ((position.y + radius) / self.cell_size).floor() as i32,
+        );
+        (min, max)
+    }
+
+    pub fn insert(&mut self, entity_id: u32, position: Vector2, radius: f32) {
+        let (min, max) = self.cell_range(position, radius);
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                self.cells.entry((x, y)).or_default().push(entity_id);
+            }
+        }
+    }
+
+    // Returns the sorted, deduplicated ids of every entity sharing a cell
+    // with the given circle. Callers still have to run the exact overlap test.
+    pub fn query(&self, position: Vector2, radius: f32) -> Vec<u32> {
+        let (min, max) = self.cell_range(position, radius);
+        let mut result = Vec::new();
+        for x in min.0..=max.0 {
+            for y in min.1..=max.1 {
+                if let Some(cell) = self.cells.get(&(x, y)) {
+                    result.extend_from_slice(cell);
+                }
+            }
+        }
+        result.sort_unstable();
+        result.dedup();
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{is_overlapping, mass_to_radius, GRID_CELL_SIZE};
+    use crate::world::Entity;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::time::Instant;
+
+    const WORLD_SIZE: f32 = 1000.0;
+
+    fn random_entities(rng: &mut StdRng, food_count: u32, circle_count: u32) -> Vec<Entity> {
+        (0..food_count + circle_count)
+            .map(|entity_id| {
+                let mass = if entity_id < food_count {
+                    rng.gen_range(2..4)
+                } else {
+                    rng.gen_range(15..2000)
+                };
+                Entity {
+                    entity_id,
+                    position: Vector2::new(
+                        rng.gen_range(0.0..WORLD_SIZE),
+                        rng.gen_range(0.0..WORLD_SIZE),
+                    ),
+                    mass,
+                }
+            })
+            .collect()
+    }
+
+    fn brute_force(circles: &[Entity], entities: &[Entity]) -> Vec<Vec<u32>> {
+        circles
+            .iter()
+            .map(|circle| {
+                entities
+                    .iter()
+                    .filter(|e| e.entity_id != circle.entity_id && is_overlapping(circle, e))
+                    .map(|e| e.entity_id)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn broadphase(circles: &[Entity], entities: &[Entity]) -> Vec<Vec<u32>> {
+        let mut grid = SpatialGrid::new(GRID_CELL_SIZE);
+        for entity in entities {
+            grid.insert(
+                entity.entity_id,
+                entity.position,
+                mass_to_radius(entity.mass),
+            );
+        }
+        circles
+            .iter()
+            .map(|circle| {
+                grid.query(circle.position, mass_to_radius(circle.mass))
+                    .into_iter()
+                    .filter(|&id| id != circle.entity_id)
+                    .filter(|&id| is_overlapping(circle, &entities[id as usize]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn query_finds_entities_in_neighbouring_cells() {
+        let mut grid = SpatialGrid::new(10.0);
+        grid.insert(1, Vector2::new(9.0, 9.0), 2.0);
+        grid.insert(2, Vector2::new(50.0, 50.0), 2.0);
+
+        assert_eq!(grid.query(Vector2::new(12.0, 12.0), 1.0), vec![1]);
+        assert!(grid.query(Vector2::new(30.0, 30.0), 1.0).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..10 {
+            let entities = random_entities(&mut rng, 3000, 300);
+            let circles: Vec<Entity> = entities[3000..].to_vec();
+            assert_eq!(
+                brute_force(&circles, &entities),
+                broadphase(&circles, &entities)
+            );
+        }
+    }
+
+    // Timing comparison, skipped by default. Run it with
+    // `cargo test --release benchmark_against_brute_force -- --ignored`.
+    #[test]
+    #[ignore]
+    fn benchmark_against_brute_force() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let entities = random_entities(&mut rng, 5000, 500);
+        let circles: Vec<Entity> = entities[5000..].to_vec();
+
+        let start = Instant::now();
+        let expected = brute_force(&circles, &entities);
+        let brute_force_time = start.elapsed();
+
+        let start = Instant::now();
+        let actual = broadphase(&circles, &entities);
+        let broadphase_time = start.elapsed();
+
+        assert_eq!(expected, actual);
+        assert!(
+            broadphase_time < brute_force_time,
+            "spatial grid ({broadphase_time:?}) should beat brute force ({brute_force_time:?})"
+        );
+    }
+}
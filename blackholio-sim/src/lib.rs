@@ -0,0 +1,14 @@
+// The Blackholio game rules, independent of SpacetimeDB so they can be
+// unit tested. The SpacetimeDB module loads its tables into a `World`,
+// steps it, and writes the result back.
+mod bot;
+mod grid;
+mod math;
+mod rules;
+mod world;
+
+pub use bot::*;
+pub use grid::SpatialGrid;
+pub use math::*;
+pub use rules::*;
+pub use world::*;
@@ -0,0 +1,118 @@
+// Plain 2D vector math, mirrored by `DbVector2` in the SpacetimeDB module.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl std::ops::Add<&Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: &Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl std::ops::Add<Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl std::ops::AddAssign<Vector2> for Vector2 {
+    fn add_assign(&mut self, rhs: Vector2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl std::iter::Sum<Vector2> for Vector2 {
+    fn sum<I: Iterator<Item = Vector2>>(iter: I) -> Self {
+        let mut r = Vector2::new(0.0, 0.0);
+        for val in iter {
+            r += val;
+        }
+        r
+    }
+}
+
+impl std::ops::Sub<&Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: &Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl std::ops::Sub<Vector2> for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl std::ops::SubAssign<Vector2> for Vector2 {
+    fn sub_assign(&mut self, rhs: Vector2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl std::ops::Mul<f32> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, other: f32) -> Vector2 {
+        Vector2 {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+impl std::ops::Div<f32> for Vector2 {
+    type Output = Vector2;
+
+    fn div(self, other: f32) -> Vector2 {
+        if other != 0.0 {
+            Vector2 {
+                x: self.x / other,
+                y: self.y / other,
+            }
+        } else {
+            Vector2 { x: 0.0, y: 0.0 }
+        }
+    }
+}
+
+impl Vector2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn sqr_magnitude(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    pub fn normalized(self) -> Vector2 {
+        self / self.magnitude()
+    }
+}
@@ -0,0 +1,134 @@
+use crate::math::Vector2;
+use crate::world::{Circle, Entity};
+
+pub const START_PLAYER_MASS: u32 = 15;
+pub const START_PLAYER_SPEED: u32 = 10;
+pub const MINIMUM_SAFE_MASS_RATIO: f32 = 0.85;
+
+pub const MIN_MASS_TO_SPLIT: u32 = START_PLAYER_MASS * 2;
+pub const MAX_CIRCLES_PER_PLAYER: usize = 16;
+pub const SPLIT_COOLDOWN_MS: u64 = 500;
+
+pub const SPLIT_RECOMBINE_DELAY_SEC: f32 = 5.0;
+pub const SPLIT_GRAV_PULL_BEFORE_RECOMBINE_SEC: f32 = 2.0;
+pub const SPLIT_GRAV_PULL_STRENGTH: f32 = 0.05;
+
+// The new half of a split is launched forward at this many units per tick,
+// losing a fraction of it every tick.
+pub const SPLIT_IMPULSE: f32 = 20.0;
+pub const SPLIT_IMPULSE_DECAY: f32 = 0.8;
+
+pub const GRID_CELL_SIZE: f32 = 32.0;
+
+// The tunable parameters of the simulation. `Default` gives the
+// values the game has always shipped with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rules {
+    pub start_player_mass: u32,
+    pub start_player_speed: u32,
+    pub minimum_safe_mass_ratio: f32,
+    pub min_mass_to_split: u32,
+    pub max_circles_per_player: usize,
+    pub split_cooldown_ms: u64,
+    pub split_recombine_delay_sec: f32,
+    pub split_grav_pull_before_recombine_sec: f32,
+    pub split_grav_pull_strength: f32,
+    pub split_impulse: f32,
+    pub split_impulse_decay: f32,
+    pub grid_cell_size: f32,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            start_player_mass: START_PLAYER_MASS,
+            start_player_speed: START_PLAYER_SPEED,
+            minimum_safe_mass_ratio: MINIMUM_SAFE_MASS_RATIO,
+            min_mass_to_split: MIN_MASS_TO_SPLIT,
+            max_circles_per_player: MAX_CIRCLES_PER_PLAYER,
+            split_cooldown_ms: SPLIT_COOLDOWN_MS,
+            split_recombine_delay_sec: SPLIT_RECOMBINE_DELAY_SEC,
+            split_grav_pull_before_recombine_sec: SPLIT_GRAV_PULL_BEFORE_RECOMBINE_SEC,
+            split_grav_pull_strength: SPLIT_GRAV_PULL_STRENGTH,
+            split_impulse: SPLIT_IMPULSE,
+            split_impulse_decay: SPLIT_IMPULSE_DECAY,
+            grid_cell_size: GRID_CELL_SIZE,
+        }
+    }
+}
+
+// The result of splitting a circle in half.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Split {
+    pub remaining_mass: u32,
+    pub split_mass: u32,
+    pub position: Vector2,
+    pub impulse: Vector2,
+}
+
+pub fn mass_to_radius(mass: u32) -> f32 {
+    (mass as f32).sqrt()
+}
+
+// Keeps a circle of `mass` entirely inside a square world.
+pub fn clamp_to_world(world_size: f32, position: Vector2, mass: u32) -> Vector2 {
+    let radius = mass_to_radius(mass);
+    let max = world_size - radius;
+    Vector2::new(position.x.clamp(radius, max), position.y.clamp(radius, max))
+}
+
+pub fn is_overlapping(a: &Entity, b: &Entity) -> bool {
+    let dx = a.position.x - b.position.x;
+    let dy = a.position.y - b.position.y;
+    let distance_sq = dx * dx + dy * dy;
+
+    let radius_a = mass_to_radius(a.mass);
+    let radius_b = mass_to_radius(b.mass);
+
+    // If the distance between the two circle centers is less than the
+    // maximum radius, then the center of the smaller circle is inside
+    // the larger circle. This gives some leeway for the circles to overlap
+    // before being eaten.
+    let max_radius = f32::max(radius_a, radius_b);
+    distance_sq <= max_radius * max_radius
+}
+
+impl Rules {
+    pub fn mass_to_max_move_speed(&self, mass: u32) -> f32 {
+        2.0 * self.start_player_speed as f32
+            / (1.0 + (mass as f32 / self.start_player_mass as f32).sqrt())
+    }
+
+    pub fn can_eat(&self, eater: &Entity, victim: &Entity) -> bool {
+        let mass_ratio = victim.mass as f32 / eater.mass as f32;
+        mass_ratio < self.minimum_safe_mass_ratio
+    }
+
+    pub fn can_recombine(&self, circle: &Circle, now_micros: i64) -> bool {
+        circle.time_since_split(now_micros) >= self.split_recombine_delay_sec
+    }
+
+    // Halves the circle if it is heavy enough and off its split cooldown.
+    // The new half is placed just in front of the circle along its direction,
+    // kept inside the world, and launched forward by `split_impulse`.
+    pub fn split(&self, circle: &Circle, now_micros: i64, world_size: f32) -> Option<Split> {
+        let cooldown_sec = self.split_cooldown_ms as f32 / 1000.0;
+        if circle.time_since_split(now_micros) < cooldown_sec {
+            return None;
+        }
+        if circle.entity.mass < self.min_mass_to_split {
+            return None;
+        }
+
+        let split_mass = circle.entity.mass / 2;
+        let remaining_mass = circle.entity.mass - split_mass;
+        let offset = mass_to_radius(remaining_mass) + mass_to_radius(split_mass);
+        let position = circle.entity.position + circle.direction * offset;
+        Some(Split {
+            remaining_mass,
+            split_mass,
+            position: clamp_to_world(world_size, position, split_mass),
+            impulse: circle.direction.normalized() * self.split_impulse,
+        })
+    }
+}
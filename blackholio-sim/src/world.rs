@@ -0,0 +1,568 @@
+use crate::grid::SpatialGrid;
+use crate::math::Vector2;
+use crate::rules::{clamp_to_world, is_overlapping, mass_to_radius, Rules};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entity {
+    pub entity_id: u32,
+    pub position: Vector2,
+    pub mass: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub entity: Entity,
+    pub player_id: u32,
+    pub direction: Vector2,
+    pub speed: f32,
+    pub last_split_micros: i64,
+    // Extra movement per tick from being split off, decaying to zero
+    pub impulse: Vector2,
+}
+
+impl Circle {
+    pub fn time_since_split(&self, now_micros: i64) -> f32 {
+        (now_micros - self.last_split_micros).max(0) as f32 / 1_000_000.0
+    }
+}
+
+// Everything that was eaten during a tick, in the order it happened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Collision {
+    FoodEaten {
+        circle_id: u32,
+        player_id: u32,
+        food_id: u32,
+        mass: u32,
+    },
+    CircleEaten {
+        circle_id: u32,
+        player_id: u32,
+        victim_id: u32,
+        victim_player_id: u32,
+        mass: u32,
+    },
+    Recombined {
+        circle_id: u32,
+        player_id: u32,
+        absorbed_id: u32,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum Slot {
+    Circle(usize),
+    Food(usize),
+}
+
+// A snapshot of the arena that one simulation tick runs on.
+#[derive(Debug, Clone)]
+pub struct World {
+    pub world_size: f32,
+    pub rules: Rules,
+    pub circles: Vec<Circle>,
+    pub food: Vec<Entity>,
+}
+
+impl World {
+    pub fn new(world_size: f32, rules: Rules) -> Self {
+        Self {
+            world_size,
+            rules,
+            circles: Vec::new(),
+            food: Vec::new(),
+        }
+    }
+
+    // Runs one tick: moves every circle, keeps split circles apart and
+    // resolves eating. Eaten circles and food are removed from the world.
+    pub fn step(&mut self, now_micros: i64) -> Vec<Collision> {
+        let directions = self.circle_directions(now_micros);
+        self.move_circles(&directions);
+        self.separate_split_circles(now_micros);
+        self.resolve_collisions(now_micros)
+    }
+
+    pub fn clamp_to_world(&self, position: Vector2, mass: u32) -> Vector2 {
+        clamp_to_world(self.world_size, position, mass)
+    }
+
+    fn circles_by_player(&self) -> HashMap<u32, Vec<usize>> {
+        let mut players: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, circle) in self.circles.iter().enumerate() {
+            players.entry(circle.player_id).or_default().push(i);
+        }
+        players
+    }
+
+    // The input direction of every circle, plus the pull of split circles
+    // towards the player's center of mass as they approach recombining.
+    fn circle_directions(&self, now_micros: i64) -> Vec<Vector2> {
+        let mut directions: Vec<Vector2> = self
+            .circles
+            .iter()
+            .map(|circle| circle.direction * circle.speed)
+            .collect();
+
+        for indices in self.circles_by_player().values() {
+            if indices.len() <= 1 {
+                continue;
+            }
+
+            let total_mass: u32 = indices.iter().map(|&i| self.circles[i].entity.mass).sum();
+            let center_of_mass = indices
+                .iter()
+                .map(|&i| self.circles[i].entity.position * self.circles[i].entity.mass as f32)
+                .sum::<Vector2>()
+                / total_mass as f32;
+
+            for &i in indices {
+                let circle = &self.circles[i];
+                let time_before_recombining = (self.rules.split_recombine_delay_sec
+                    - circle.time_since_split(now_micros))
+                .max(0.0);
+                if time_before_recombining > self.rules.split_grav_pull_before_recombine_sec {
+                    continue;
+                }
+
+                let gravity_multiplier = if self.rules.split_grav_pull_before_recombine_sec > 0.0 {
+                    1.0 - time_before_recombining / self.rules.split_grav_pull_before_recombine_sec
+                } else {
+                    1.0
+                };
+                let to_center = center_of_mass - circle.entity.position;
+                let pull = (to_center.magnitude() * self.rules.split_grav_pull_strength).min(1.0);
+                directions[i] += to_center.normalized() * pull * gravity_multiplier;
+            }
+        }
+
+        directions
+    }
+
+    fn move_circles(&mut self, directions: &[Vector2]) {
+        for (i, &direction) in directions.iter().enumerate() {
+            let Circle {
+                entity, impulse, ..
+            } = self.circles[i];
+            let new_pos = entity.position
+                + direction * self.rules.mass_to_max_move_speed(entity.mass)
+                + impulse;
+            self.circles[i].entity.position = self.clamp_to_world(new_pos, entity.mass);
+
+            let impulse = impulse * self.rules.split_impulse_decay;
+            self.circles[i].impulse = if impulse.sqr_magnitude() < 0.01 {
+                Vector2::default()
+            } else {
+                impulse
+            };
+        }
+    }
+
+    fn separate_split_circles(&mut self, now_micros: i64) {
+        for indices in self.circles_by_player().values() {
+            for (n, &i) in indices.iter().enumerate() {
+                for &j in &indices[n + 1..] {
+                    let (circle_i, circle_j) = (self.circles[i], self.circles[j]);
+                    if self.rules.can_recombine(&circle_i, now_micros)
+                        && self.rules.can_recombine(&circle_j, now_micros)
+                    {
+                        continue;
+                    }
+
+                    let (entity_i, entity_j) = (circle_i.entity, circle_j.entity);
+                    let mut diff = entity_i.position - entity_j.position;
+                    if diff.sqr_magnitude() <= 0.0001 {
+                        diff = Vector2::new(1.0, 0.0);
+                    }
+                    let distance = diff.magnitude();
+                    let radius_sum = mass_to_radius(entity_i.mass) + mass_to_radius(entity_j.mass);
+                    if distance >= radius_sum {
+                        continue;
+                    }
+
+                    // Push both circles out of each other, the lighter one moving further
+                    let total_mass = (entity_i.mass + entity_j.mass) as f32;
+                    let push = diff.normalized() * (radius_sum - distance);
+                    let position_i = entity_i.position + push * (entity_j.mass as f32 / total_mass);
+                    let position_j = entity_j.position - push * (entity_i.mass as f32 / total_mass);
+                    self.circles[i].entity.position =
+                        self.clamp_to_world(position_i, entity_i.mass);
+                    self.circles[j].entity.position =
+                        self.clamp_to_world(position_j, entity_j.mass);
+                }
+            }
+        }
+    }
+
+    fn resolve_collisions(&mut self, now_micros: i64) -> Vec<Collision> {
+        let mut grid = SpatialGrid::new(self.rules.grid_cell_size);
+        let mut slots = HashMap::new();
+        for (i, circle) in self.circles.iter().enumerate() {
+            let entity = &circle.entity;
+            grid.insert(
+                entity.entity_id,
+                entity.position,
+                mass_to_radius(entity.mass),
+            );
+            slots.insert(entity.entity_id, Slot::Circle(i));
+        }
+        for (i, food) in self.food.iter().enumerate() {
+            grid.insert(food.entity_id, food.position, mass_to_radius(food.mass));
+            slots.insert(food.entity_id, Slot::Food(i));
+        }
+
+        let mut eaten = HashSet::new();
+        let mut collisions = Vec::new();
+        for i in 0..self.circles.len() {
+            let circle = self.circles[i];
+            let circle_id = circle.entity.entity_id;
+            if eaten.contains(&circle_id) {
+                continue;
+            }
+
+            let candidates = grid.query(circle.entity.position, mass_to_radius(circle.entity.mass));
+            for other_id in candidates {
+                if other_id == circle_id || eaten.contains(&other_id) {
+                    continue;
+                }
+
+                let eater = self.circles[i].entity;
+                match slots[&other_id] {
+                    Slot::Food(j) => {
+                        let food = self.food[j];
+                        if !is_overlapping(&eater, &food) {
+                            continue;
+                        }
+                        eaten.insert(other_id);
+                        self.circles[i].entity.mass += food.mass;
+                        collisions.push(Collision::FoodEaten {
+                            circle_id,
+                            player_id: circle.player_id,
+                            food_id: other_id,
+                            mass: food.mass,
+                        });
+                    }
+                    Slot::Circle(j) => {
+                        let other = self.circles[j];
+                        if !is_overlapping(&eater, &other.entity) {
+                            continue;
+                        }
+                        if other.player_id != circle.player_id {
+                            if !self.rules.can_eat(&eater, &other.entity) {
+                                continue;
+                            }
+                            eaten.insert(other_id);
+                            self.circles[i].entity.mass += other.entity.mass;
+                            collisions.push(Collision::CircleEaten {
+                                circle_id,
+                                player_id: circle.player_id,
+                                victim_id: other_id,
+                                victim_player_id: other.player_id,
+                                mass: other.entity.mass,
+                            });
+                        } else if self.rules.can_recombine(&circle, now_micros)
+                            && self.rules.can_recombine(&other, now_micros)
+                        {
+                            // Recombine our own split circles into this one
+                            eaten.insert(other_id);
+                            self.circles[i].entity.mass += other.entity.mass;
+                            collisions.push(Collision::Recombined {
+                                circle_id,
+                                player_id: circle.player_id,
+                                absorbed_id: other_id,
+                            });
+                        }
+                    }
+                }
+            }
+
+            // A circle that grew now covers more cells than it was registered in
+            let entity = self.circles[i].entity;
+            if entity.mass != circle.entity.mass {
+                grid.insert(
+                    entity.entity_id,
+                    entity.position,
+                    mass_to_radius(entity.mass),
+                );
+            }
+        }
+
+        self.circles
+            .retain(|circle| !eaten.contains(&circle.entity.entity_id));
+        self.food.retain(|food| !eaten.contains(&food.entity_id));
+        collisions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{START_PLAYER_MASS, START_PLAYER_SPEED};
+
+    const WORLD_SIZE: f32 = 1000.0;
+    const SECOND: i64 = 1_000_000;
+
+    fn world() -> World {
+        World::new(WORLD_SIZE, Rules::default())
+    }
+
+    fn entity(entity_id: u32, x: f32, y: f32, mass: u32) -> Entity {
+        Entity {
+            entity_id,
+            position: Vector2::new(x, y),
+            mass,
+        }
+    }
+
+    fn circle(entity_id: u32, player_id: u32, x: f32, y: f32, mass: u32) -> Circle {
+        Circle {
+            entity: entity(entity_id, x, y, mass),
+            player_id,
+            direction: Vector2::new(0.0, 1.0),
+            speed: 0.0,
+            last_split_micros: 0,
+            impulse: Vector2::default(),
+        }
+    }
+
+    fn find(world: &World, entity_id: u32) -> Option<&Circle> {
+        world
+            .circles
+            .iter()
+            .find(|circle| circle.entity.entity_id == entity_id)
+    }
+
+    #[test]
+    fn max_move_speed_shrinks_with_mass() {
+        let rules = Rules::default();
+        assert_eq!(
+            rules.mass_to_max_move_speed(START_PLAYER_MASS),
+            START_PLAYER_SPEED as f32
+        );
+        assert!(rules.mass_to_max_move_speed(START_PLAYER_MASS * 4) < START_PLAYER_SPEED as f32);
+    }
+
+    #[test]
+    fn circle_moves_along_direction() {
+        let mut world = world();
+        let mut c = circle(1, 1, 500.0, 500.0, START_PLAYER_MASS);
+        c.direction = Vector2::new(1.0, 0.0);
+        c.speed = 0.5;
+        world.circles.push(c);
+
+        world.step(10 * SECOND);
+
+        let moved = find(&world, 1).unwrap();
+        assert_eq!(moved.entity.position, Vector2::new(505.0, 500.0));
+    }
+
+    #[test]
+    fn idle_circle_stays_put() {
+        let mut world = world();
+        world
+            .circles
+            .push(circle(1, 1, 500.0, 500.0, START_PLAYER_MASS));
+
+        world.step(10 * SECOND);
+
+        assert_eq!(
+            find(&world, 1).unwrap().entity.position,
+            Vector2::new(500.0, 500.0)
+        );
+    }
+
+    #[test]
+    fn circle_is_clamped_at_world_edge() {
+        let mut world = world();
+        let mut c = circle(1, 1, 2.0, WORLD_SIZE - 2.0, 100);
+        c.direction = Vector2::new(-1.0, 1.0).normalized();
+        c.speed = 1.0;
+        world.circles.push(c);
+
+        world.step(10 * SECOND);
+
+        let radius = mass_to_radius(100);
+        let position = find(&world, 1).unwrap().entity.position;
+        assert_eq!(position, Vector2::new(radius, WORLD_SIZE - radius));
+    }
+
+    #[test]
+    fn circle_eats_overlapping_food() {
+        let mut world = world();
+        world
+            .circles
+            .push(circle(1, 1, 500.0, 500.0, START_PLAYER_MASS));
+        world.food.push(entity(2, 502.0, 500.0, 3));
+        world.food.push(entity(3, 600.0, 600.0, 3));
+
+        let collisions = world.step(10 * SECOND);
+
+        assert_eq!(
+            collisions,
+            vec![Collision::FoodEaten {
+                circle_id: 1,
+                player_id: 1,
+                food_id: 2,
+                mass: 3,
+            }]
+        );
+        assert_eq!(find(&world, 1).unwrap().entity.mass, START_PLAYER_MASS + 3);
+        assert_eq!(world.food, vec![entity(3, 600.0, 600.0, 3)]);
+    }
+
+    #[test]
+    fn larger_circle_eats_smaller_player() {
+        let mut world = world();
+        world.circles.push(circle(1, 1, 500.0, 500.0, 100));
+        world.circles.push(circle(2, 2, 505.0, 500.0, 50));
+
+        let collisions = world.step(10 * SECOND);
+
+        assert_eq!(
+            collisions,
+            vec![Collision::CircleEaten {
+                circle_id: 1,
+                player_id: 1,
+                victim_id: 2,
+                victim_player_id: 2,
+                mass: 50,
+            }]
+        );
+        assert_eq!(world.circles.len(), 1);
+        assert_eq!(find(&world, 1).unwrap().entity.mass, 150);
+    }
+
+    #[test]
+    fn smaller_circle_cannot_eat_larger_player() {
+        let mut world = world();
+        world.circles.push(circle(1, 1, 505.0, 500.0, 50));
+        world.circles.push(circle(2, 2, 500.0, 500.0, 100));
+
+        let collisions = world.step(10 * SECOND);
+
+        assert_eq!(collisions.len(), 1);
+        assert!(find(&world, 1).is_none());
+        assert_eq!(find(&world, 2).unwrap().entity.mass, 150);
+    }
+
+    #[test]
+    fn similar_masses_are_safe_from_each_other() {
+        // 86 / 100 is above the 0.85 safe mass ratio, so nobody gets eaten
+        let mut world = world();
+        world.circles.push(circle(1, 1, 500.0, 500.0, 100));
+        world.circles.push(circle(2, 2, 505.0, 500.0, 86));
+
+        let collisions = world.step(10 * SECOND);
+
+        assert!(collisions.is_empty());
+        assert_eq!(world.circles.len(), 2);
+    }
+
+    #[test]
+    fn mass_ratio_just_below_threshold_is_eaten() {
+        // 84 / 100 is below the 0.85 safe mass ratio
+        let mut world = world();
+        world.circles.push(circle(1, 1, 500.0, 500.0, 100));
+        world.circles.push(circle(2, 2, 505.0, 500.0, 84));
+
+        world.step(10 * SECOND);
+
+        assert_eq!(world.circles.len(), 1);
+        assert_eq!(find(&world, 1).unwrap().entity.mass, 184);
+    }
+
+    #[test]
+    fn split_circles_stay_apart_before_recombine_delay() {
+        let mut world = world();
+        let mut a = circle(1, 1, 500.0, 500.0, 100);
+        let mut b = circle(2, 1, 505.0, 500.0, 100);
+        a.last_split_micros = 9 * SECOND;
+        b.last_split_micros = 9 * SECOND;
+        world.circles.push(a);
+        world.circles.push(b);
+
+        let collisions = world.step(10 * SECOND);
+
+        assert!(collisions.is_empty());
+        let a = find(&world, 1).unwrap().entity;
+        let b = find(&world, 2).unwrap().entity;
+        let distance = (a.position - b.position).magnitude();
+        assert!(distance >= mass_to_radius(100) * 2.0 - 0.001);
+    }
+
+    #[test]
+    fn split_circles_merge_after_recombine_delay() {
+        let mut world = world();
+        world.circles.push(circle(1, 1, 500.0, 500.0, 100));
+        world.circles.push(circle(2, 1, 505.0, 500.0, 60));
+
+        let collisions = world.step(10 * SECOND);
+
+        assert_eq!(
+            collisions,
+            vec![Collision::Recombined {
+                circle_id: 1,
+                player_id: 1,
+                absorbed_id: 2,
+            }]
+        );
+        assert_eq!(world.circles.len(), 1);
+        assert_eq!(find(&world, 1).unwrap().entity.mass, 160);
+    }
+
+    #[test]
+    fn split_halves_heavy_circle() {
+        let rules = Rules::default();
+        let mut c = circle(1, 1, 500.0, 500.0, 101);
+        c.direction = Vector2::new(1.0, 0.0);
+
+        let split = rules.split(&c, 10 * SECOND, 1000.0).unwrap();
+
+        assert_eq!(split.remaining_mass, 51);
+        assert_eq!(split.split_mass, 50);
+        assert!(split.position.x > 500.0);
+        assert_eq!(split.position.y, 500.0);
+        assert_eq!(split.impulse, Vector2::new(rules.split_impulse, 0.0));
+    }
+
+    #[test]
+    fn split_near_wall_stays_inside_world() {
+        let rules = Rules::default();
+        let mut c = circle(1, 1, 990.0, 500.0, 101);
+        c.direction = Vector2::new(1.0, 0.0);
+
+        let split = rules.split(&c, 10 * SECOND, 1000.0).unwrap();
+
+        assert!(split.position.x + mass_to_radius(split.split_mass) <= 1000.0);
+    }
+
+    #[test]
+    fn split_impulse_moves_circle_and_decays() {
+        let mut world = World::new(1000.0, Rules::default());
+        let mut c = circle(1, 1, 500.0, 500.0, 50);
+        c.impulse = Vector2::new(10.0, 0.0);
+        world.circles.push(c);
+
+        world.step(10 * SECOND);
+        let moved = find(&world, 1).unwrap();
+        assert_eq!(moved.entity.position.x, 510.0);
+        assert_eq!(moved.impulse.x, 10.0 * world.rules.split_impulse_decay);
+
+        for _ in 0..100 {
+            world.step(10 * SECOND);
+        }
+        assert_eq!(find(&world, 1).unwrap().impulse, Vector2::default());
+    }
+
+    #[test]
+    fn split_respects_min_mass_and_cooldown() {
+        let rules = Rules::default();
+        let light = circle(1, 1, 500.0, 500.0, rules.min_mass_to_split - 1);
+        assert!(rules.split(&light, 10 * SECOND, 1000.0).is_none());
+
+        let mut recent = circle(2, 1, 500.0, 500.0, 200);
+        recent.last_split_micros = 10 * SECOND - 100_000;
+        assert!(rules.split(&recent, 10 * SECOND, 1000.0).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use super::*;
 use crate::global_state::*;
-use godot::classes::{Camera2D, ICamera2D};
+use godot::classes::{Camera2D, ICamera2D, Viewport};
 use std::ops::Deref;
 use std::sync::atomic::AtomicU32;
 
@@ -78,7 +78,48 @@ impl ICamera2D for CameraController {
     }
 }
 
+/// 摄像机当前可见的世界区域
+#[derive(Clone, Copy, PartialEq)]
+pub struct CameraView {
+    /// 可见矩形（世界坐标）
+    pub bounds: Rect2,
+    /// 屏幕中心对应的世界坐标
+    pub center: Vector2,
+    /// 缩放，世界单位到屏幕像素的比例
+    pub zoom: f32,
+}
+
+impl CameraView {
+    /// 获取视口当前激活的摄像机视图，没有摄像机时视口矩形即为世界坐标
+    pub fn from_viewport(viewport: &Gd<Viewport>) -> Self {
+        let viewport_rect = viewport.get_visible_rect();
+        match viewport
+            .get_camera_2d()
+            .and_then(|camera| camera.try_cast::<CameraController>().ok())
+        {
+            Some(camera) => camera.bind().view(),
+            None => Self {
+                bounds: viewport_rect,
+                center: viewport_rect.center(),
+                zoom: 1.0,
+            },
+        }
+    }
+}
+
 impl CameraController {
+    /// 根据摄像机位置和缩放计算可见区域
+    pub fn view(&self) -> CameraView {
+        let center = self.base().get_screen_center_position();
+        let zoom = self.base().get_zoom();
+        let size = self.base().get_viewport_rect().size / zoom;
+        CameraView {
+            bounds: Rect2::new(center - size * 0.5, size),
+            center,
+            zoom: zoom.x,
+        }
+    }
+
     fn calculate_camera_size(&self, player: Gd<PlayerController>) -> f32 {
         50.0 + f32::min(50.0, player.bind().total_mass() as f32 / 5.0)
             + isize::min(player.bind().number_of_owned_circles() - 1, 1) as f32 * 30.0
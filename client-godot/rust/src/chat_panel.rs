@@ -0,0 +1,156 @@
+use super::*;
+use crate::global_state::*;
+use crate::global_state::main_menu;
+use godot::classes::{
+    CanvasLayer, ICanvasLayer, InputEvent, InputEventKey, LineEdit, RichTextLabel, VBoxContainer,
+};
+use godot::global::Key;
+
+/// 聊天面板
+///
+/// 显示本地玩家所在竞技场的最近消息，按 Enter 输入并发送
+#[derive(GodotClass)]
+#[class(init, base=CanvasLayer)]
+pub struct ChatPanel {
+    base: Base<CanvasLayer>,
+
+    messages: Option<Gd<RichTextLabel>>,
+    input: Option<Gd<LineEdit>>,
+}
+
+impl ChatPanel {
+    const MAX_VISIBLE_MESSAGES: usize = 20;
+
+    /// 转义 BBCode，避免玩家输入的 `[` 被当作标签解析
+    fn escape_bbcode(text: &str) -> String {
+        text.replace('[', "[lb]")
+    }
+
+    /// 根据聊天记录生成 BBCode 文本
+    fn build_text(&self) -> String {
+        let Some(conn) = connection::get_connection() else {
+            return String::new();
+        };
+        let Some(arena_id) = local_arena_id() else {
+            return String::new();
+        };
+
+        let mut messages: Vec<ChatMessage> = conn
+            .db
+            .chat_message()
+            .iter()
+            .filter(|x| x.arena_id == arena_id)
+            .collect();
+        messages.sort_by_key(|x| x.message_id);
+        let skip = messages.len().saturating_sub(Self::MAX_VISIBLE_MESSAGES);
+
+        let mut text = String::new();
+        for message in messages.into_iter().skip(skip) {
+            // 已离线玩家不在 player 表中
+            let name = conn
+                .db
+                .player()
+                .player_id()
+                .find(&message.player_id)
+                .map(|x| x.name)
+                .unwrap_or_else(|| "Unknown".to_string());
+            text.push_str(&format!(
+                "[b]{}:[/b] {}\n",
+                Self::escape_bbcode(&name),
+                Self::escape_bbcode(&message.text)
+            ));
+        }
+        text
+    }
+}
+
+#[godot_api]
+impl ICanvasLayer for ChatPanel {
+    fn ready(&mut self) {
+        let mut container = VBoxContainer::new_alloc();
+        container.set_name("ChatContainer");
+
+        // 固定在屏幕左下角
+        container.set_anchor(godot::builtin::Side::TOP, 1.0);
+        container.set_anchor(godot::builtin::Side::BOTTOM, 1.0);
+        container.set_offset(godot::builtin::Side::LEFT, 10.0);
+        container.set_offset(godot::builtin::Side::TOP, -410.0);
+        container.set_offset(godot::builtin::Side::RIGHT, 610.0);
+        container.set_offset(godot::builtin::Side::BOTTOM, -10.0);
+        container.set_mouse_filter(godot::classes::control::MouseFilter::IGNORE);
+
+        let mut messages = RichTextLabel::new_alloc();
+        messages.set_use_bbcode(true);
+        messages.set_scroll_follow(true);
+        messages.set_v_size_flags(godot::classes::control::SizeFlags::EXPAND_FILL);
+        messages.set_mouse_filter(godot::classes::control::MouseFilter::IGNORE);
+        messages.add_theme_font_size_override("normal_font_size", 20);
+        messages.add_theme_font_size_override("bold_font_size", 20);
+        container.add_child(&messages);
+
+        let mut input = LineEdit::new_alloc();
+        input.set_placeholder("Press Enter to chat");
+        input.set_max_length(200);
+        input.connect("text_submitted", &self.base().callable("on_text_submitted"));
+        container.add_child(&input);
+
+        self.base_mut().add_child(&container);
+        self.messages = Some(messages);
+        self.input = Some(input);
+    }
+
+    /// 只在聊天记录或玩家变化后重建文本
+    fn process(&mut self, _delta: f64) {
+        if !hud::take_chat_dirty() {
+            return;
+        }
+
+        let text = self.build_text();
+        if let Some(messages) = &mut self.messages {
+            messages.set_text(&text);
+        }
+    }
+
+    /// 在 GUI 之后处理，聚焦的输入框（例如主菜单的名字）按 Enter 时不会被抢走
+    fn unhandled_input(&mut self, event: Gd<InputEvent>) {
+        let Ok(key) = event.try_cast::<InputEventKey>() else {
+            return;
+        };
+        if !key.is_pressed() || key.is_echo() || key.get_keycode() != Key::ENTER {
+            return;
+        }
+
+        // 只在游戏中、没有其他控件聚焦时按 Enter 开始输入
+        let mut viewport = self.base().get_viewport().unwrap();
+        if viewport.gui_get_focus_owner().is_some() {
+            return;
+        }
+        if main_menu::get_instance().is_none_or(|menu| menu.is_visible()) {
+            return;
+        }
+        if let Some(input) = &mut self.input {
+            input.grab_focus();
+            viewport.set_input_as_handled();
+        }
+    }
+}
+
+#[godot_api]
+impl ChatPanel {
+    #[func]
+    fn on_text_submitted(&mut self, text: GString) {
+        let text = text.to_string();
+        if !text.trim().is_empty() {
+            if let Some(conn) = connection::get_connection() {
+                if let Err(e) = conn.reducers.send_chat(text) {
+                    show_error(&format!("Failed to send message: {}", e));
+                }
+            }
+        }
+
+        if let Some(input) = &mut self.input {
+            input.clear();
+            input.release_focus();
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use super::*;
+use crate::global_state::*;
+use crate::entity_controller::mass_to_scale;
+use godot::classes::{Font, INode2D, ThemeDb};
+use std::collections::HashMap;
+
+/// 圆形批量渲染器
+///
+/// 替代单独的 CircleController 节点，圆形的位置（插值/预测）、MultiMesh 实例
+/// 和名字都由这里根据 Circle/Entity 表维护，一次绘制调用渲染所有圆形
+#[derive(GodotClass)]
+#[class(init, base=Node2D)]
+pub struct CircleBatchRenderer {
+    base: Base<Node2D>,
+
+    /// 圆形渲染数据
+    circles: CircleStore,
+
+    /// 实例化渲染器
+    renderer: Option<Gd<MultiMeshRenderer>>,
+
+    /// 实例缓冲区，每帧复用
+    instances: InstanceBuffer,
+
+    /// 名字绘制缓冲区，每帧复用
+    labels: Vec<(Vector2, Vector2, CircleLabel)>,
+}
+
+/// 圆形渲染数据
+pub struct CircleRenderData {
+    /// 实体ID
+    pub entity_id: u32,
+    /// 所属玩家
+    pub player_id: u32,
+    /// 显示在圆形上的玩家名字
+    pub name: String,
+    /// 当前渲染位置
+    pub position: Vector2,
+    /// 当前缩放
+    pub scale: Vector2,
+    /// 渲染颜色
+    pub color: Color,
+    /// 服务器状态快照
+    pub snapshots: SnapshotBuffer,
+    /// 仅本地玩家的圆形启用客户端预测
+    pub prediction: Option<CirclePrediction>,
+    /// 缓存的名字文本，第一次绘制时创建，名字变化时清除
+    label: Option<CircleLabel>,
+}
+
+/// 排版好的名字，避免每帧创建字符串和测量文字
+#[derive(Clone)]
+struct CircleLabel {
+    text: GString,
+    /// 以圆心为文字中心时的绘制偏移
+    offset: Vector2,
+}
+
+impl CircleLabel {
+    fn new(font: &Gd<Font>, name: &str) -> Self {
+        let text = GString::from(name);
+        // 以圆心为文字中心，基线在中心下方
+        let size = font
+            .get_string_size_ex(&text)
+            .font_size(NAME_FONT_SIZE)
+            .done();
+        let ascent = font.get_ascent_ex().font_size(NAME_FONT_SIZE).done();
+        let offset = Vector2::new(-size.x * 0.5, ascent - size.y * 0.5);
+        Self { text, offset }
+    }
+}
+
+const COLOR_PALETTE: &[Color] = &[
+    //Yellow
+    Color::from_rgba8(175, 159, 49, 255),
+    Color::from_rgba8(175, 116, 49, 255),
+    //Purple
+    Color::from_rgba8(112, 47, 252, 255),
+    Color::from_rgba8(51, 91, 252, 255),
+    //Red
+    Color::from_rgba8(176, 54, 54, 255),
+    Color::from_rgba8(176, 109, 54, 255),
+    Color::from_rgba8(141, 43, 99, 255),
+    //Blue
+    Color::from_rgba8(2, 188, 250, 255),
+    Color::from_rgba8(7, 50, 251, 255),
+    Color::from_rgba8(2, 28, 146, 255),
+];
+
+/// 圆形的基础大小，与缩放相乘即为直径
+const CIRCLE_SIZE: f32 = 100.0;
+
+/// 名字的字号和描边，随圆形一起缩放
+const NAME_FONT_SIZE: i32 = 32;
+const NAME_OUTLINE_SIZE: i32 = 10;
+
+impl CircleRenderData {
+    /// 根据 Entity 表中的数据创建渲染数据
+    pub fn from_entity(entity: &Entity, player_id: u32, name: String, time: f64) -> Self {
+        let position: Vector2 = (&entity.position).into();
+        let scale = mass_to_scale(entity.mass);
+        let color = COLOR_PALETTE[(entity.entity_id as usize) % COLOR_PALETTE.len()];
+
+        let mut snapshots = SnapshotBuffer::default();
+        snapshots.push(time, position, scale);
+
+        Self {
+            entity_id: entity.entity_id,
+            player_id,
+            name,
+            position,
+            scale,
+            color,
+            snapshots,
+            prediction: None,
+            label: None,
+        }
+    }
+
+    /// 玩家改名后在下一次绘制时重新排版
+    fn set_name(&mut self, name: &str) {
+        if self.name != name {
+            self.name = name.to_string();
+            self.label = None;
+        }
+    }
+
+    /// 记录新的服务器状态
+    fn on_entity_updated(&mut self, entity: &Entity, time: f64) {
+        if let Some(prediction) = &mut self.prediction {
+            prediction.on_authoritative_update(entity);
+        }
+        self.snapshots.push(time, (&entity.position).into(), mass_to_scale(entity.mass));
+    }
+
+    /// 按渲染时间采样快照；预测的圆形按本地输入更新位置，缩放仍来自快照
+    fn update(&mut self, render_time: f64, input: Vector2, delta: f32) {
+        if let Some((position, scale)) = self.snapshots.sample(render_time) {
+            self.position = position;
+            self.scale = scale;
+        }
+        if let Some(prediction) = &mut self.prediction {
+            self.position = prediction.update(input, delta);
+        }
+    }
+
+    fn diameter(&self) -> f32 {
+        self.scale.x * CIRCLE_SIZE
+    }
+}
+
+/// 圆形的紧凑存储，删除时与末尾元素交换
+#[derive(Default)]
+pub struct CircleStore {
+    circles: Vec<CircleRenderData>,
+    /// entity_id 到 `circles` 下标的映射
+    indices: HashMap<u32, usize>,
+}
+
+impl CircleStore {
+    pub fn insert(&mut self, circle_data: CircleRenderData) {
+        match self.indices.get(&circle_data.entity_id) {
+            Some(&index) => self.circles[index] = circle_data,
+            None => {
+                self.indices.insert(circle_data.entity_id, self.circles.len());
+                self.circles.push(circle_data);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, entity_id: u32) -> Option<CircleRenderData> {
+        let index = self.indices.remove(&entity_id)?;
+        let removed = self.circles.swap_remove(index);
+        if let Some(moved) = self.circles.get(index) {
+            self.indices.insert(moved.entity_id, index);
+        }
+        Some(removed)
+    }
+
+    pub fn get(&self, entity_id: u32) -> Option<&CircleRenderData> {
+        self.indices.get(&entity_id).map(|&index| &self.circles[index])
+    }
+
+    fn get_mut(&mut self, entity_id: u32) -> Option<&mut CircleRenderData> {
+        self.indices
+            .get(&entity_id)
+            .map(|&index| &mut self.circles[index])
+    }
+
+    pub fn contains(&self, entity_id: u32) -> bool {
+        self.indices.contains_key(&entity_id)
+    }
+
+    pub fn clear(&mut self) {
+        self.circles.clear();
+        self.indices.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.circles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.circles.is_empty()
+    }
+
+    /// 推进所有圆形的插值和预测
+    pub fn update(&mut self, render_time: f64, input: Vector2, delta: f32) {
+        for circle_data in &mut self.circles {
+            circle_data.update(render_time, input, delta);
+        }
+    }
+
+    /// 更新玩家所有圆形上的名字
+    pub fn rename_player(&mut self, player_id: u32, name: &str) {
+        for circle_data in &mut self.circles {
+            if circle_data.player_id == player_id {
+                circle_data.set_name(name);
+            }
+        }
+    }
+
+    /// 把所有圆形写入实例缓冲区
+    pub fn write_instances(&self, instances: &mut InstanceBuffer) {
+        instances.clear();
+        for circle_data in &self.circles {
+            instances.push(circle_data.position, circle_data.diameter(), circle_data.color);
+        }
+    }
+}
+
+impl CircleBatchRenderer {
+    /// 添加圆形，本地玩家的圆形启用客户端预测
+    pub fn add_circle(&mut self, circle: &Circle, name: String, predicted: bool) {
+        let Some(conn) = connection::get_connection() else {
+            return;
+        };
+        let Some(entity) = conn.db.entity().entity_id().find(&circle.entity_id) else {
+            return;
+        };
+
+        let mut circle_data =
+            CircleRenderData::from_entity(&entity, circle.player_id, name, snapshot_time(&entity));
+        if predicted {
+            circle_data.prediction = Some(CirclePrediction::new(&entity));
+        }
+        self.circles.insert(circle_data);
+    }
+
+    /// 移除圆形，返回其所属玩家
+    pub fn remove_circle(&mut self, entity_id: u32) -> Option<u32> {
+        self.circles.remove(entity_id).map(|x| x.player_id)
+    }
+
+    /// 更新圆形实体数据（通常在entity_on_update时调用）
+    pub fn update_circle_entity(&mut self, entity: &Entity) {
+        let time = snapshot_time(entity);
+        if let Some(circle_data) = self.circles.get_mut(entity.entity_id) {
+            circle_data.on_entity_updated(entity, time);
+        }
+    }
+
+    /// 玩家改名（通常在player_on_update时调用）
+    pub fn rename_player(&mut self, player_id: u32, name: &str) {
+        self.circles.rename_player(player_id, name);
+    }
+
+    /// 移除所有圆形（断线重连时使用）
+    pub fn clear_circles(&mut self) {
+        self.circles.clear();
+    }
+
+    /// 检查是否包含指定圆形
+    pub fn contains_circle(&self, entity_id: u32) -> bool {
+        self.circles.contains(entity_id)
+    }
+
+    /// 圆形当前的渲染位置
+    pub fn circle_position(&self, entity_id: u32) -> Option<Vector2> {
+        self.circles.get(entity_id).map(|x| x.position)
+    }
+
+    /// 圆形的预测状态，仅本地玩家的圆形有
+    pub fn circle_prediction(&self, entity_id: u32) -> Option<&CirclePrediction> {
+        self.circles.get(entity_id)?.prediction.as_ref()
+    }
+}
+
+#[godot_api]
+impl INode2D for CircleBatchRenderer {
+    fn ready(&mut self) {
+        let mut renderer = MultiMeshRenderer::new_alloc();
+        renderer.set_name("CircleMultiMesh");
+        // 名字在本节点的 draw 中绘制，圆形需要画在它下面
+        renderer.set_show_behind_parent(true);
+        self.base_mut().add_child(&renderer);
+        self.renderer = Some(renderer);
+
+        // 在 PlayerController 更新输入之后再处理
+        self.base_mut().set_process_priority(100);
+    }
+
+    fn process(&mut self, delta: f64) {
+        let input = players::get_local_player()
+            .map(|x| x.bind().input_direction)
+            .unwrap_or_default();
+        self.circles
+            .update(interpolation::render_time(), input, delta as f32);
+
+        self.circles.write_instances(&mut self.instances);
+        if let Some(renderer) = &mut self.renderer {
+            renderer.bind_mut().upload(&self.instances);
+        }
+        self.base_mut().queue_redraw();
+    }
+
+    fn draw(&mut self) {
+        let font = ThemeDb::singleton().get_fallback_font();
+        let Some(font) = font else {
+            return;
+        };
+
+        // 复制名字只增加 GString 的引用计数，缓冲区在帧之间复用
+        let mut labels = std::mem::take(&mut self.labels);
+        labels.clear();
+        for circle_data in &mut self.circles.circles {
+            let label = circle_data
+                .label
+                .get_or_insert_with(|| CircleLabel::new(&font, &circle_data.name));
+            labels.push((circle_data.position, circle_data.scale, label.clone()));
+        }
+
+        let mut base = self.base_mut();
+        for (position, scale, label) in &labels {
+            base.draw_set_transform_ex(*position).scale(*scale).done();
+            base.draw_string_outline_ex(&font, label.offset, &label.text)
+                .font_size(NAME_FONT_SIZE)
+                .size(NAME_OUTLINE_SIZE)
+                .modulate(Color::BLACK)
+                .done();
+            base.draw_string_ex(&font, label.offset, &label.text)
+                .font_size(NAME_FONT_SIZE)
+                .done();
+        }
+        base.draw_set_transform(Vector2::ZERO);
+        drop(base);
+        self.labels = labels;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module_bindings::DbVector2;
+
+    fn circle_entity(entity_id: u32, x: f32, y: f32, mass: u32) -> Entity {
+        Entity {
+            entity_id,
+            arena_id: 1,
+            position: DbVector2 { x, y },
+            mass,
+        }
+    }
+
+    fn store_of(entities: &[Entity]) -> CircleStore {
+        let mut store = CircleStore::default();
+        for entity in entities {
+            store.insert(CircleRenderData::from_entity(entity, 7, "Alice".to_string(), 0.0));
+        }
+        store
+    }
+
+    #[test]
+    fn instance_buffer_matches_circle_table() {
+        let entities = [
+            circle_entity(1, 100.0, 200.0, 15),
+            circle_entity(2, -50.0, 40.0, 60),
+            circle_entity(3, 0.0, 0.0, 240),
+        ];
+        let mut store = store_of(&entities);
+        store.update(0.0, Vector2::ZERO, 1.0 / 60.0);
+        let mut instances = InstanceBuffer::default();
+        store.write_instances(&mut instances);
+
+        assert_eq!(instances.len(), entities.len());
+        for (instance, entity) in instances
+            .as_slice()
+            .chunks(FLOATS_PER_INSTANCE)
+            .zip(&entities)
+        {
+            let diameter = mass_to_diameter(entity.mass);
+            let color = COLOR_PALETTE[entity.entity_id as usize % COLOR_PALETTE.len()];
+            assert!((instance[0] - diameter).abs() < 1e-3);
+            assert!((instance[5] - diameter).abs() < 1e-3);
+            assert_eq!(instance[3], entity.position.x);
+            assert_eq!(instance[7], entity.position.y);
+            assert_eq!(&instance[8..12], &[color.r, color.g, color.b, color.a]);
+        }
+    }
+
+    #[test]
+    fn removing_circle_keeps_indices_consistent() {
+        let entities: Vec<Entity> = (1..=3)
+            .map(|entity_id| circle_entity(entity_id, entity_id as f32 * 10.0, 0.0, 15))
+            .collect();
+        let mut store = store_of(&entities);
+
+        // 删除第一个元素后，最后一个元素被移到它的位置
+        assert_eq!(store.remove(1).map(|x| x.player_id), Some(7));
+        assert!(store.remove(1).is_none());
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get(3).map(|x| x.position), Some(Vector2::new(30.0, 0.0)));
+        assert_eq!(store.get(2).map(|x| x.position), Some(Vector2::new(20.0, 0.0)));
+    }
+}
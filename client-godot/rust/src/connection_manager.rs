@@ -0,0 +1,148 @@
+/// 连接状态，用于在界面上显示
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionStatus {
+    /// 正在连接
+    Connecting,
+    /// 已连接，等待订阅数据
+    Connected,
+    /// 订阅数据已就绪，可以进入游戏
+    Ready,
+    /// 连接断开，等待重连
+    Reconnecting { delay_sec: f64 },
+    /// 无法连接，不会重试
+    Failed(String),
+}
+
+impl ConnectionStatus {
+    pub fn description(&self) -> String {
+        match self {
+            ConnectionStatus::Connecting => "Connecting...".to_string(),
+            ConnectionStatus::Connected => "Connected, loading world...".to_string(),
+            ConnectionStatus::Ready => "Connected".to_string(),
+            ConnectionStatus::Reconnecting { delay_sec } => {
+                format!("Connection lost, retrying in {:.0} s", delay_sec)
+            }
+            ConnectionStatus::Failed(message) => format!("Cannot connect: {}", message),
+        }
+    }
+}
+
+/// 断线重连的指数退避
+///
+/// 每次失败后等待时间翻倍，直到上限；连接成功后重置
+#[derive(Default)]
+pub struct ReconnectBackoff {
+    /// 连续失败的次数
+    attempt: u32,
+    /// 下一次重连的时间（秒），为 None 时不需要重连
+    next_attempt_at: Option<f64>,
+    /// 主动断开后不再重连
+    stopped: bool,
+}
+
+impl ReconnectBackoff {
+    const BASE_DELAY_SEC: f64 = 1.0;
+    const MAX_DELAY_SEC: f64 = 30.0;
+
+    /// 第 `attempt` 次失败后的等待时间
+    pub fn delay(attempt: u32) -> f64 {
+        let factor = 2f64.powi(attempt.min(16) as i32);
+        f64::min(Self::BASE_DELAY_SEC * factor, Self::MAX_DELAY_SEC)
+    }
+
+    /// 连接成功
+    pub fn on_connected(&mut self) {
+        self.attempt = 0;
+        self.next_attempt_at = None;
+    }
+
+    /// 连接断开或失败，安排下一次重连并返回等待时间
+    ///
+    /// 同一次断开可能被报告多次（frame_tick 出错和断开回调），已有计划时不再重复安排
+    pub fn schedule(&mut self, now: f64) -> Option<f64> {
+        if self.stopped || self.is_pending() {
+            return None;
+        }
+        let delay = Self::delay(self.attempt);
+        self.attempt += 1;
+        self.next_attempt_at = Some(now + delay);
+        Some(delay)
+    }
+
+    /// 是否到了重连时间，返回 true 后清除计划
+    pub fn take_due(&mut self, now: f64) -> bool {
+        match self.next_attempt_at {
+            Some(at) if !self.stopped && now >= at => {
+                self.next_attempt_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 是否已安排重连
+    pub fn is_pending(&self) -> bool {
+        self.next_attempt_at.is_some()
+    }
+
+    /// 主动断开，不再重连
+    pub fn stop(&mut self) {
+        self.stopped = true;
+        self.next_attempt_at = None;
+    }
+
+    /// 连续失败的次数
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_up_to_the_cap() {
+        assert_eq!(ReconnectBackoff::delay(0), 1.0);
+        assert_eq!(ReconnectBackoff::delay(1), 2.0);
+        assert_eq!(ReconnectBackoff::delay(4), 16.0);
+        assert_eq!(ReconnectBackoff::delay(5), 30.0);
+        assert_eq!(ReconnectBackoff::delay(u32::MAX), 30.0);
+    }
+
+    #[test]
+    fn reconnects_when_due_and_resets_on_connect() {
+        let mut backoff = ReconnectBackoff::default();
+        assert_eq!(backoff.schedule(10.0), Some(1.0));
+        assert!(!backoff.take_due(10.5));
+        assert!(backoff.take_due(11.0));
+        assert!(!backoff.take_due(12.0));
+
+        assert_eq!(backoff.schedule(12.0), Some(2.0));
+        assert_eq!(backoff.attempt(), 2);
+        backoff.on_connected();
+        assert_eq!(backoff.attempt(), 0);
+        assert_eq!(backoff.schedule(20.0), Some(1.0));
+    }
+
+    #[test]
+    fn repeated_failure_reports_schedule_once() {
+        let mut backoff = ReconnectBackoff::default();
+        assert_eq!(backoff.schedule(0.0), Some(1.0));
+        assert_eq!(backoff.schedule(0.0), None);
+        assert_eq!(backoff.attempt(), 1);
+
+        assert!(backoff.take_due(1.0));
+        assert!(!backoff.is_pending());
+        assert_eq!(backoff.schedule(1.0), Some(2.0));
+    }
+
+    #[test]
+    fn stopped_backoff_never_reconnects() {
+        let mut backoff = ReconnectBackoff::default();
+        backoff.schedule(0.0);
+        backoff.stop();
+        assert!(!backoff.take_due(100.0));
+        assert_eq!(backoff.schedule(100.0), None);
+    }
+}
@@ -0,0 +1,153 @@
+/// 连接设置
+///
+/// 优先级从低到高：GameManager 导出属性、环境变量、命令行用户参数
+/// （`godot -- --server-url http://host:3000 --module blackholio --profile alice --guest`）
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConnectionSettings {
+    pub server_url: String,
+    pub module_name: String,
+    /// 凭据配置名，不同配置使用不同的凭据文件和身份
+    pub profile: String,
+    /// 访客模式不保存令牌，每次启动都是新的身份
+    pub guest: bool,
+}
+
+impl Default for ConnectionSettings {
+    fn default() -> Self {
+        Self {
+            server_url: "http://127.0.0.1:3000".to_string(),
+            module_name: "blackholio".to_string(),
+            profile: String::new(),
+            guest: false,
+        }
+    }
+}
+
+impl ConnectionSettings {
+    const CREDENTIALS_KEY: &'static str = "blackholio";
+
+    /// 用环境变量覆盖设置
+    pub fn apply_env<F>(&mut self, env: F)
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(url) = env("BLACKHOLIO_SERVER_URL") {
+            self.server_url = url;
+        }
+        if let Some(module_name) = env("BLACKHOLIO_MODULE") {
+            self.module_name = module_name;
+        }
+        if let Some(profile) = env("BLACKHOLIO_PROFILE") {
+            self.profile = profile;
+        }
+        if let Some(guest) = env("BLACKHOLIO_GUEST") {
+            self.guest = matches!(guest.to_ascii_lowercase().as_str(), "1" | "true" | "yes");
+        }
+    }
+
+    /// 用命令行用户参数覆盖设置，支持 `--name value` 和 `--name=value`
+    pub fn apply_args(&mut self, args: &[String]) -> Result<(), String> {
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+
+            if name == "--guest" {
+                self.guest = true;
+                continue;
+            }
+
+            let target = match name {
+                "--server-url" => &mut self.server_url,
+                "--module" => &mut self.module_name,
+                "--profile" => &mut self.profile,
+                _ => return Err(format!("Unknown argument {}", arg)),
+            };
+            *target = match inline_value {
+                Some(value) => value,
+                None => args
+                    .next()
+                    .cloned()
+                    .ok_or_else(|| format!("{} needs a value", name))?,
+            };
+        }
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.server_url.trim().is_empty() {
+            return Err("Server URL must not be empty".into());
+        }
+        if self.module_name.trim().is_empty() {
+            return Err("Module name must not be empty".into());
+        }
+        // 配置名会成为文件名的一部分
+        if !self
+            .profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err("Profile may only contain letters, digits, '_' and '-'".into());
+        }
+        Ok(())
+    }
+
+    /// 凭据文件名，默认配置沿用原来的文件
+    pub fn credentials_key(&self) -> String {
+        if self.profile.is_empty() {
+            Self::CREDENTIALS_KEY.to_string()
+        } else {
+            format!("{}-{}", Self::CREDENTIALS_KEY, self.profile)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn args_override_env_and_defaults() {
+        let mut settings = ConnectionSettings::default();
+        settings.apply_env(|name| match name {
+            "BLACKHOLIO_SERVER_URL" => Some("http://env:3000".to_string()),
+            "BLACKHOLIO_PROFILE" => Some("env".to_string()),
+            _ => None,
+        });
+        settings
+            .apply_args(&args(&["--profile", "alice", "--module=other", "--guest"]))
+            .unwrap();
+
+        assert_eq!(
+            settings,
+            ConnectionSettings {
+                server_url: "http://env:3000".to_string(),
+                module_name: "other".to_string(),
+                profile: "alice".to_string(),
+                guest: true,
+            }
+        );
+        assert_eq!(settings.credentials_key(), "blackholio-alice");
+    }
+
+    #[test]
+    fn default_profile_keeps_the_original_credentials_file() {
+        assert_eq!(ConnectionSettings::default().credentials_key(), "blackholio");
+    }
+
+    #[test]
+    fn invalid_args_are_rejected() {
+        let mut settings = ConnectionSettings::default();
+        assert!(settings.apply_args(&args(&["--profile"])).is_err());
+        assert!(settings.apply_args(&args(&["--unknown"])).is_err());
+
+        settings.apply_args(&args(&["--profile", "../x"])).unwrap();
+        assert!(settings.validate().is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::global_state::*;
+use godot::classes::{Button, CanvasLayer, ColorRect, ICanvasLayer, Label, VBoxContainer};
+
+/// 死亡界面
+///
+/// 本地玩家失去所有圆圈后显示，包含击杀者、最终质量和重生按钮
+#[derive(GodotClass)]
+#[class(init, base=CanvasLayer)]
+pub struct DeathScreen {
+    base: Base<CanvasLayer>,
+
+    label: Option<Gd<Label>>,
+    button: Option<Gd<Button>>,
+    error_label: Option<Gd<Label>>,
+}
+
+impl DeathScreen {
+    /// 根据最近一次本地玩家被吃的事件生成提示文本
+    fn build_text(local_player_id: u32) -> String {
+        let Some(conn) = connection::get_connection() else {
+            return "You died".to_string();
+        };
+
+        let last_kill = conn
+            .db
+            .kill_event()
+            .iter()
+            .filter(|x| x.victim_player_id == local_player_id)
+            .max_by_key(|x| x.event_id);
+        let Some(kill) = last_kill else {
+            return "You died".to_string();
+        };
+
+        let killer_name = conn
+            .db
+            .player()
+            .player_id()
+            .find(&kill.killer_player_id)
+            .map(|x| x.name)
+            .filter(|x| !x.is_empty())
+            .unwrap_or_else(|| "Unknown".to_string());
+        // 事件只在最后一个圆圈被吃掉时写入，victim_mass 为死亡时的总质量
+        format!(
+            "You were eaten by {}\nFinal mass: {}",
+            killer_name, kill.victim_mass
+        )
+    }
+
+    /// 显示死亡界面
+    pub fn show_death(&mut self, local_player_id: u32) {
+        let text = Self::build_text(local_player_id);
+        if let Some(label) = &mut self.label {
+            label.set_text(&text);
+        }
+        self.set_respawning(false, "");
+        self.base_mut().set_visible(true);
+    }
+
+    /// 本地玩家的新圆圈已生成，关闭死亡界面
+    pub fn on_respawned(&mut self) {
+        self.set_respawning(false, "");
+        self.base_mut().set_visible(false);
+    }
+
+    /// 服务器拒绝了重生请求，例如请求过于频繁，保持界面打开以便重试
+    pub fn on_respawn_failed(&mut self, message: &str) {
+        self.set_respawning(false, message);
+    }
+
+    fn set_respawning(&mut self, respawning: bool, error: &str) {
+        if let Some(button) = &mut self.button {
+            button.set_disabled(respawning);
+        }
+        if let Some(label) = &mut self.error_label {
+            label.set_text(error);
+        }
+    }
+}
+
+#[godot_api]
+impl ICanvasLayer for DeathScreen {
+    fn ready(&mut self) {
+        // 半透明背景
+        let mut background = ColorRect::new_alloc();
+        background.set_color(Color::from_rgba(0.0, 0.0, 0.0, 0.6));
+        background.set_anchor(godot::builtin::Side::RIGHT, 1.0);
+        background.set_anchor(godot::builtin::Side::BOTTOM, 1.0);
+        self.base_mut().add_child(&background);
+
+        let mut container = VBoxContainer::new_alloc();
+        container.set_anchor(godot::builtin::Side::LEFT, 0.5);
+        container.set_anchor(godot::builtin::Side::TOP, 0.5);
+        container.set_anchor(godot::builtin::Side::RIGHT, 0.5);
+        container.set_anchor(godot::builtin::Side::BOTTOM, 0.5);
+        container.set_offset(godot::builtin::Side::LEFT, -250.0);
+        container.set_offset(godot::builtin::Side::TOP, -100.0);
+        container.set_offset(godot::builtin::Side::RIGHT, 250.0);
+        container.set_offset(godot::builtin::Side::BOTTOM, 100.0);
+
+        let mut label = Label::new_alloc();
+        label.set_horizontal_alignment(godot::global::HorizontalAlignment::CENTER);
+        label.add_theme_font_size_override("font_size", 32);
+        container.add_child(&label);
+
+        let mut button = Button::new_alloc();
+        button.set_text("Respawn");
+        button.add_theme_font_size_override("font_size", 32);
+        button.connect("pressed", &self.base().callable("on_respawn_pressed"));
+        container.add_child(&button);
+
+        let mut error_label = Label::new_alloc();
+        error_label.set_horizontal_alignment(godot::global::HorizontalAlignment::CENTER);
+        error_label.add_theme_color_override("font_color", Color::from_rgb(1.0, 0.4, 0.4));
+        container.add_child(&error_label);
+
+        self.base_mut().add_child(&container);
+        self.label = Some(label);
+        self.button = Some(button);
+        self.error_label = Some(error_label);
+        self.base_mut().set_visible(false);
+    }
+}
+
+#[godot_api]
+impl DeathScreen {
+    /// 界面在新圆圈到达后才关闭，重生失败时可以再次点击
+    #[func]
+    fn on_respawn_pressed(&mut self) {
+        let Some(conn) = connection::get_connection() else {
+            return;
+        };
+        match conn.reducers.respawn() {
+            Ok(()) => self.set_respawning(true, ""),
+            Err(e) => self.set_respawning(false, &format!("Failed to respawn: {}", e)),
+        }
+    }
+}
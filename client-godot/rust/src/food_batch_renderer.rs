@@ -1,31 +1,36 @@
 use super::*;
 use crate::global_state::*;
-use crate::module_bindings::Food;
+use crate::module_bindings::{Entity, Food};
 use crate::entity_controller::mass_to_scale;
-use godot::classes::{Control, IControl, Texture2D};
+use godot::classes::{Control, IControl};
 use godot::prelude::*;
 use std::collections::HashMap;
 
 /// 食物批量渲染器
 /// 
-/// 替代单独的FoodController节点，使用图形API批量绘制所有食物
+/// 替代单独的FoodController节点，通过 MultiMesh 在一次绘制调用中渲染所有食物
 #[derive(GodotClass)]
 #[class(init, base=Control)]
 pub struct FoodBatchRenderer {
     base: Base<Control>,
     
-    /// 食物渲染数据映射表
-    food_instances: HashMap<u32, FoodRenderData>,
+    /// 食物渲染数据
+    foods: FoodStore,
     
     /// 是否需要重绘
     needs_redraw: bool,
     
-    /// 食物纹理
-    texture: Option<Gd<Texture2D>>,
+    /// 实例化渲染器
+    renderer: Option<Gd<MultiMeshRenderer>>,
+
+    /// 实例缓冲区，每次重建时复用
+    instances: InstanceBuffer,
+
+    /// 上一次绘制时的摄像机视图，摄像机移动或缩放时需要重绘
+    last_camera_view: Option<CameraView>,
 }
 
 /// 食物渲染数据
-#[derive(Clone)]
 pub struct FoodRenderData {
     /// 实体ID
     pub entity_id: u32,
@@ -35,32 +40,10 @@ pub struct FoodRenderData {
     pub scale: Vector2,
     /// 渲染颜色
     pub color: Color,
-    /// 插值动画数据
-    pub lerp_data: LerpData,
-}
-
-/// 插值动画数据
-#[derive(Clone)]
-pub struct LerpData {
-    /// 插值时间
-    pub lerp_time: f32,
-    /// 起始位置
-    pub start_position: Vector2,
-    /// 目标位置
-    pub target_position: Vector2,
-    /// 目标缩放
-    pub target_scale: Vector2,
-}
-
-impl Default for LerpData {
-    fn default() -> Self {
-        Self {
-            lerp_time: 0.0,
-            start_position: Vector2::ZERO,
-            target_position: Vector2::ZERO,
-            target_scale: Vector2::ONE,
-        }
-    }
+    /// 服务器状态快照
+    pub snapshots: SnapshotBuffer,
+    /// 是否在 FoodStore 的插值列表中
+    animating: bool,
 }
 
 /// 食物颜色调色板（与原FoodController保持一致）
@@ -74,19 +57,229 @@ const COLOR_PALETTE: &[Color] = &[
 ];
 
 const FOOD_SIZE: Vector2 = Vector2::new(100.0, 100.0); // 基础食物大小
-const LERP_DURATION_SEC: f32 = 0.1; // 插值动画持续时间
 
 // 性能优化相关常量
-const CULLING_MARGIN: f32 = 100.0; // 视锥剔除边距
-const LOD_DISTANCE_HIGH: f32 = 200.0; // 高质量LOD距离
-const LOD_DISTANCE_MEDIUM: f32 = 500.0; // 中等质量LOD距离
+const CULLING_MARGIN: f32 = 100.0; // 视锥剔除边距（世界单位）
+const LOD_DISTANCE_HIGH: f32 = 400.0; // 高质量LOD距离（屏幕像素）
+const LOD_DISTANCE_MEDIUM: f32 = 800.0; // 中等质量LOD距离（屏幕像素）
+const LOD_SCREEN_SIZE_HIGH: f32 = 16.0; // 高质量LOD最小屏幕尺寸（像素）
+const LOD_SCREEN_SIZE_LOW: f32 = 6.0; // 小于该屏幕尺寸时只绘制圆点（像素）
 
 /// 食物LOD级别
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum FoodLOD {
-    High,    // 完整纹理
-    Medium,  // 简化纹理
-    Low,     // 单色圆点
+    High,    // 完整大小
+    Medium,  // 稍小
+    Low,     // 小圆点
+}
+
+impl FoodLOD {
+    /// 绘制直径相对于实际直径的比例
+    fn size_factor(self) -> f32 {
+        match self {
+            FoodLOD::High => 1.0,
+            FoodLOD::Medium => 0.8,
+            FoodLOD::Low => 0.6,
+        }
+    }
+}
+
+impl FoodRenderData {
+    /// 根据 Entity 表中的数据创建渲染数据
+    pub fn from_entity(entity: &Entity, time: f64) -> Self {
+        let position: Vector2 = (&entity.position).into();
+        let scale = mass_to_scale(entity.mass);
+
+        // 选择颜色（根据entity_id）
+        let color_index = (entity.entity_id as usize) % COLOR_PALETTE.len();
+        let color = COLOR_PALETTE[color_index];
+
+        let mut snapshots = SnapshotBuffer::default();
+        snapshots.push(time, position, scale);
+
+        Self {
+            entity_id: entity.entity_id,
+            position,
+            scale,
+            color,
+            snapshots,
+            animating: false,
+        }
+    }
+
+    /// 按渲染时间采样快照，返回是否有变化
+    fn sample(&mut self, render_time: f64) -> bool {
+        let Some((position, scale)) = self.snapshots.sample(render_time) else {
+            return false;
+        };
+        if position == self.position && scale == self.scale {
+            return false; // 没有变化，不需要重绘
+        }
+
+        self.position = position;
+        self.scale = scale;
+        true // 需要重绘
+    }
+}
+
+/// 食物的紧凑存储
+///
+/// 渲染数据连续存放，每帧只处理插值仍在进行的食物
+#[derive(Default)]
+pub struct FoodStore {
+    /// 渲染数据，删除时与末尾元素交换
+    foods: Vec<FoodRenderData>,
+    /// entity_id 到 `foods` 下标的映射
+    indices: HashMap<u32, usize>,
+    /// 插值仍在进行的食物
+    animating: Vec<u32>,
+}
+
+impl FoodStore {
+    pub fn insert(&mut self, food_data: FoodRenderData) {
+        match self.indices.get(&food_data.entity_id) {
+            Some(&index) => {
+                // 保留插值列表中的状态，由 animate 在插值结束时移出
+                let animating = self.foods[index].animating;
+                self.foods[index] = food_data;
+                self.foods[index].animating = animating;
+            }
+            None => {
+                self.indices.insert(food_data.entity_id, self.foods.len());
+                self.foods.push(food_data);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, entity_id: u32) -> bool {
+        let Some(index) = self.indices.remove(&entity_id) else {
+            return false;
+        };
+        let removed = self.foods.swap_remove(index);
+        if let Some(moved) = self.foods.get(index) {
+            self.indices.insert(moved.entity_id, index);
+        }
+        if removed.animating {
+            self.animating.retain(|&x| x != entity_id);
+        }
+        true
+    }
+
+    /// 记录新的服务器快照，并开始插值
+    pub fn push_snapshot(&mut self, entity_id: u32, time: f64, position: Vector2, scale: Vector2) -> bool {
+        let Some(&index) = self.indices.get(&entity_id) else {
+            return false;
+        };
+        self.foods[index].snapshots.push(time, position, scale);
+        self.set_animating(entity_id);
+        true
+    }
+
+    fn set_animating(&mut self, entity_id: u32) {
+        let food_data = &mut self.foods[self.indices[&entity_id]];
+        if !food_data.animating {
+            food_data.animating = true;
+            self.animating.push(entity_id);
+        }
+    }
+
+    pub fn contains(&self, entity_id: u32) -> bool {
+        self.indices.contains_key(&entity_id)
+    }
+
+    pub fn clear(&mut self) {
+        self.foods.clear();
+        self.indices.clear();
+        self.animating.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.foods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.foods.is_empty()
+    }
+
+    /// 正在插值的食物数量
+    pub fn animating_count(&self) -> usize {
+        self.animating.len()
+    }
+
+    /// 推进正在插值的食物，插值结束的移出列表，返回是否有位置或缩放变化
+    pub fn animate(&mut self, render_time: f64) -> bool {
+        let foods = &mut self.foods;
+        let indices = &self.indices;
+        let mut changed = false;
+        self.animating.retain(|entity_id| {
+            let Some(&index) = indices.get(entity_id) else {
+                return false;
+            };
+            let food_data = &mut foods[index];
+            changed |= food_data.sample(render_time);
+            if food_data.snapshots.is_settled(render_time) {
+                food_data.animating = false;
+                return false;
+            }
+            true
+        });
+        changed
+    }
+
+    /// 把可见的食物写入实例缓冲区，返回写入的数量
+    pub fn write_instances(&self, instances: &mut InstanceBuffer, camera_view: &CameraView) -> usize {
+        instances.clear();
+        self.foods
+            .iter()
+            .filter(|food_data| write_food_instance(instances, food_data, camera_view))
+            .count()
+    }
+}
+
+/// 性能优化：视锥剔除检查
+fn should_render_food(food_data: &FoodRenderData, camera_bounds: Rect2) -> bool {
+    let food_bounds = Rect2::new(
+        food_data.position - (food_data.scale * FOOD_SIZE) * 0.5,
+        food_data.scale * FOOD_SIZE
+    );
+    // 添加边距以确保边缘食物也能正常显示
+    let extended_camera_bounds = Rect2::new(
+        camera_bounds.position - Vector2::splat(CULLING_MARGIN),
+        camera_bounds.size + Vector2::splat(CULLING_MARGIN * 2.0)
+    );
+    extended_camera_bounds.intersects(food_bounds)
+}
+
+/// 性能优化：根据到屏幕中心的距离和屏幕上的尺寸获取食物LOD级别
+fn get_food_lod(distance_on_screen: f32, size_on_screen: f32) -> FoodLOD {
+    if size_on_screen < LOD_SCREEN_SIZE_LOW {
+        FoodLOD::Low
+    } else if distance_on_screen < LOD_DISTANCE_HIGH && size_on_screen >= LOD_SCREEN_SIZE_HIGH {
+        FoodLOD::High
+    } else if distance_on_screen < LOD_DISTANCE_MEDIUM {
+        FoodLOD::Medium
+    } else {
+        FoodLOD::Low
+    }
+}
+
+/// 把一个食物写入实例缓冲区，被剔除时返回 false
+fn write_food_instance(
+    instances: &mut InstanceBuffer,
+    food_data: &FoodRenderData,
+    camera_view: &CameraView,
+) -> bool {
+    if !should_render_food(food_data, camera_view.bounds) {
+        return false;
+    }
+
+    // 根据到摄像机中心的距离和屏幕尺寸选择LOD
+    let diameter = food_data.scale.x * FOOD_SIZE.x;
+    let distance = food_data.position.distance_to(camera_view.center) * camera_view.zoom;
+    let lod = get_food_lod(distance, diameter * camera_view.zoom);
+
+    instances.push(food_data.position, diameter * lod.size_factor(), food_data.color);
+    true
 }
 
 impl FoodBatchRenderer {
@@ -95,126 +288,70 @@ impl FoodBatchRenderer {
         // 从Entity表获取位置和质量信息
         if let Some(conn) = connection::get_connection() {
             if let Some(entity) = conn.db.entity().entity_id().find(&food.entity_id) {
-                let position: Vector2 = entity.position.into();
-                let scale = mass_to_scale(entity.mass);
-                
-                // 选择颜色（根据entity_id）
-                let color_index = (food.entity_id as usize) % COLOR_PALETTE.len();
-                let color = COLOR_PALETTE[color_index];
-                
-                let food_data = FoodRenderData {
-                    entity_id: food.entity_id,
-                    position,
-                    scale,
-                    color,
-                    lerp_data: LerpData {
-                        lerp_time: 0.0,
-                        start_position: position,
-                        target_position: position,
-                        target_scale: scale,
-                    },
-                };
-                
-                self.food_instances.insert(food.entity_id, food_data);
+                let food_data = FoodRenderData::from_entity(&entity, snapshot_time(&entity));
+                godot_print!("Food {} added to batch renderer at position {:?}", food.entity_id, food_data.position);
+
+                self.foods.insert(food_data);
                 self.needs_redraw = true;
-                
-                godot_print!("Food {} added to batch renderer at position {:?}", food.entity_id, position);
             }
         }
     }
     
     /// 从批量渲染器移除食物
     pub fn remove_food(&mut self, entity_id: u32) {
-        if self.food_instances.remove(&entity_id).is_some() {
+        if self.foods.remove(entity_id) {
             self.needs_redraw = true;
             godot_print!("Food {} removed from batch renderer", entity_id);
         }
     }
     
     /// 更新食物实体数据（通常在entity_on_update时调用）
-    pub fn update_food_entity(&mut self, entity: &crate::module_bindings::Entity) {
-        if let Some(food_data) = self.food_instances.get_mut(&entity.entity_id) {
-            // 重置插值动画
-            food_data.lerp_data.lerp_time = 0.0;
-            food_data.lerp_data.start_position = food_data.position;
-            food_data.lerp_data.target_position = (&entity.position).into();
-            food_data.lerp_data.target_scale = mass_to_scale(entity.mass);
-            
+    pub fn update_food_entity(&mut self, entity: &Entity) {
+        // 记录新的服务器快照
+        let updated = self.foods.push_snapshot(
+            entity.entity_id,
+            snapshot_time(entity),
+            (&entity.position).into(),
+            mass_to_scale(entity.mass),
+        );
+        if updated {
             self.needs_redraw = true;
-            
-            godot_print!("Food entity {} updated in batch renderer", entity.entity_id);
         }
     }
     
+    /// 移除所有食物（断线重连时使用）
+    pub fn clear_food(&mut self) {
+        self.foods.clear();
+        self.needs_redraw = true;
+    }
+
     /// 检查是否包含指定食物
     pub fn contains_food(&self, entity_id: u32) -> bool {
-        self.food_instances.contains_key(&entity_id)
+        self.foods.contains(entity_id)
     }
     
     /// 获取食物数量（用于调试）
     pub fn get_food_count(&self) -> usize {
-        self.food_instances.len()
-    }
-    
-    /// 性能优化：视锥剔除检查
-    fn should_render_food(&self, food_data: &FoodRenderData, camera_bounds: Rect2) -> bool {
-        let food_bounds = Rect2::new(
-            food_data.position - (food_data.scale * FOOD_SIZE) * 0.5,
-            food_data.scale * FOOD_SIZE
-        );
-        // 添加边距以确保边缘食物也能正常显示
-        let extended_camera_bounds = Rect2::new(
-            camera_bounds.position - Vector2::splat(CULLING_MARGIN),
-            camera_bounds.size + Vector2::splat(CULLING_MARGIN * 2.0)
-        );
-        extended_camera_bounds.intersects(food_bounds)
-    }
-    
-    /// 性能优化：获取食物LOD级别
-    fn get_food_lod(&self, distance_to_camera: f32) -> FoodLOD {
-        if distance_to_camera < LOD_DISTANCE_HIGH {
-            FoodLOD::High
-        } else if distance_to_camera < LOD_DISTANCE_MEDIUM {
-            FoodLOD::Medium
-        } else {
-            FoodLOD::Low
-        }
+        self.foods.len()
     }
     
-    /// 计算食物到摄像机的距离
-    fn calculate_distance_to_camera(&self, food_position: Vector2) -> f32 {
-        // 简单的距离计算，实际项目中可以使用摄像机位置
-        // 这里假设摄像机在原点附近
-        food_position.length()
+    /// 获取当前摄像机视图
+    fn camera_view(&self) -> Option<CameraView> {
+        self.base().get_viewport().map(|viewport| CameraView::from_viewport(&viewport))
     }
     
-    /// 更新食物插值动画
-    fn update_food_lerp(&mut self, food_data: &mut FoodRenderData, delta: f32) -> bool {
-        if food_data.lerp_data.lerp_time < LERP_DURATION_SEC {
-            food_data.lerp_data.lerp_time = f32::min(
-                food_data.lerp_data.lerp_time + delta,
-                LERP_DURATION_SEC
-            );
-            
-            let t = food_data.lerp_data.lerp_time / LERP_DURATION_SEC;
-            
-            // 位置插值
-            food_data.position = Vector2::lerp(
-                food_data.lerp_data.start_position,
-                food_data.lerp_data.target_position,
-                t
-            );
-            
-            // 缩放插值
-            food_data.scale = Vector2::lerp(
-                food_data.scale,
-                food_data.lerp_data.target_scale,
-                delta * 8.0
-            );
-            
-            true // 需要重绘
-        } else {
-            false // 动画完成，不需要重绘
+    /// 按当前摄像机视图重建实例缓冲区并上传
+    fn rebuild_instances(&mut self) {
+        // 从当前激活的摄像机获取可见区域
+        let Some(camera_view) = self.camera_view() else {
+            return;
+        };
+        self.last_camera_view = Some(camera_view);
+
+        // 实例缓冲区和上传缓冲区都会复用，容量足够时不再分配
+        self.foods.write_instances(&mut self.instances, &camera_view);
+        if let Some(renderer) = &mut self.renderer {
+            renderer.bind_mut().upload(&self.instances);
         }
     }
 }
@@ -222,18 +359,13 @@ impl FoodBatchRenderer {
 #[godot_api]
 impl IControl for FoodBatchRenderer {
     /// 初始化
-    fn ready(&mut self) {        
-        // 尝试加载食物纹理，如果失败则创建简单纹理
-        match load::<Texture2D>("res://icon.svg") {
-            texture => self.texture = Some(texture),
-        }
-        
-        // 如果仍然没有纹理，则创建一个简单的白色纹理
-        if self.texture.is_none() {
-            godot_warn!("Could not load food texture, using fallback rendering");
-        }
-        
-        godot_print!("FoodBatchRenderer ready and registered with {} foods", self.food_instances.len());
+    fn ready(&mut self) {
+        let mut renderer = MultiMeshRenderer::new_alloc();
+        renderer.set_name("FoodMultiMesh");
+        self.base_mut().add_child(&renderer);
+        self.renderer = Some(renderer);
+
+        godot_print!("FoodBatchRenderer ready and registered with {} foods", self.foods.len());
         godot_print!("FoodBatchRenderer instance: {:?}", self.base().instance_id());
         
         // 启用处理以便定期重绘
@@ -241,130 +373,195 @@ impl IControl for FoodBatchRenderer {
     }
     
     /// 每帧处理
-    fn process(&mut self, delta: f64) {
-        let delta = delta as f32;
-        let mut needs_redraw = false;
+    fn process(&mut self, _delta: f64) {
+        let render_time = interpolation::render_time();
         
-        // 更新所有食物的插值动画
-        let mut entities_to_update = Vec::new();
-        for (entity_id, food_data) in &self.food_instances {
-            entities_to_update.push((*entity_id, food_data.clone()));
-        }
+        // 只更新插值仍在进行的食物
+        let mut needs_redraw = self.foods.animate(render_time);
         
-        for (entity_id, mut food_data) in entities_to_update {
-            if self.update_food_lerp(&mut food_data, delta) {
-                needs_redraw = true;
-                self.food_instances.insert(entity_id, food_data);
-            }
+        // 摄像机移动或缩放后，剔除和LOD结果会变化
+        let camera_view = self.camera_view();
+        if camera_view != self.last_camera_view {
+            needs_redraw = true;
         }
         
-        // 如果有动画更新或标记需要重绘，则重绘
+        // 如果有动画更新或标记需要重绘，则重建实例
         if needs_redraw || self.needs_redraw {
-            self.base_mut().queue_redraw();
+            self.rebuild_instances();
             self.needs_redraw = false;
         }
     }
-    
-    /// 批量绘制所有食物（带性能优化）
-    fn draw(&mut self) {
-        // 先收集所有绘制数据避免借用冲突
-        let foods_to_draw: Vec<FoodRenderData> = self.food_instances.values().cloned().collect();
-        
-        if foods_to_draw.is_empty() {
-            return; // 没有食物需要绘制
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module_bindings::DbVector2;
+
+    fn food_entity(entity_id: u32, x: f32, y: f32, mass: u32) -> Entity {
+        Entity {
+            entity_id,
+            arena_id: 1,
+            position: DbVector2 { x, y },
+            mass,
         }
-        
-        godot_print!("Drawing {} foods", foods_to_draw.len());
-        
-        // 简单的摄像机边界计算（实际项目中应该从摄像机获取）
-        let camera_bounds = Rect2::new(
-            Vector2::new(-1000.0, -1000.0),
-            Vector2::new(2000.0, 2000.0)
-        );
-        
-        let mut rendered_count = 0;
-        let mut culled_count = 0;
-        
-        // 提前克隆纹理以避免借用冲突
-        let texture = self.texture.clone();
-        
-        for food_data in foods_to_draw {
-            // 视锥剔除检查
-            if !self.should_render_food(&food_data, camera_bounds) {
-                culled_count += 1;
-                continue;
-            }
-            
-            // 计算到摄像机的距离
-            let distance = self.calculate_distance_to_camera(food_data.position);
-            let lod = self.get_food_lod(distance);
-            
-            // 计算绘制矩形
-            let draw_rect = Rect2::new(
-                food_data.position - (food_data.scale * FOOD_SIZE) * 0.5,
-                food_data.scale * FOOD_SIZE
+    }
+
+    fn camera_view(center: Vector2, size: f32, zoom: f32) -> CameraView {
+        CameraView {
+            bounds: Rect2::new(center - Vector2::splat(size * 0.5), Vector2::splat(size)),
+            center,
+            zoom,
+        }
+    }
+
+    fn write_all(entities: &[Entity], camera_view: &CameraView) -> InstanceBuffer {
+        let mut instances = InstanceBuffer::default();
+        for entity in entities {
+            write_food_instance(
+                &mut instances,
+                &FoodRenderData::from_entity(entity, 0.0),
+                camera_view,
             );
-            
-            match lod {
-                FoodLOD::High => {
-                    if let Some(ref tex) = texture {
-                        // 高质量：完整纹理绘制
-                        self.base_mut().set_modulate(food_data.color);
-                        self.base_mut().draw_texture_rect(
-                            tex,
-                            draw_rect,
-                            false
-                        );
-                    } else {
-                        // 无纹理时使用圆形
-                        self.base_mut().draw_circle(
-                            food_data.position,
-                            (food_data.scale.x * FOOD_SIZE.x) * 0.5,
-                            food_data.color
-                        );
-                    }
-                }
-                FoodLOD::Medium => {
-                    if let Some(ref tex) = texture {
-                        // 中等质量：稍小的纹理
-                        let smaller_rect = Rect2::new(
-                            draw_rect.position + draw_rect.size * 0.1,
-                            draw_rect.size * 0.8
-                        );
-                        self.base_mut().set_modulate(food_data.color);
-                        self.base_mut().draw_texture_rect(
-                            tex,
-                            smaller_rect,
-                            false
-                        );
-                    } else {
-                        // 无纹理时使用较小圆形
-                        self.base_mut().draw_circle(
-                            food_data.position,
-                            (food_data.scale.x * FOOD_SIZE.x) * 0.4,
-                            food_data.color
-                        );
-                    }
-                }
-                FoodLOD::Low => {
-                    // 低质量：简单圆点
-                    self.base_mut().draw_circle(
-                        food_data.position,
-                        (food_data.scale.x * FOOD_SIZE.x) * 0.3,
-                        food_data.color
-                    );
-                }
-            }
-            
-            rendered_count += 1;
         }
-        
-        // 重置颜色调制
-        self.base_mut().set_modulate(Color::WHITE);
-        
-        // 调试信息
-        if rendered_count > 0 {
-            godot_print!("Food rendering: {} rendered, {} culled", rendered_count, culled_count);
+        instances
+    }
+
+    #[test]
+    fn instance_buffer_matches_entity_table() {
+        let entities = [
+            food_entity(1, 10.0, 20.0, 2),
+            food_entity(2, -15.0, 5.0, 4),
+            food_entity(3, 0.0, -30.0, 3),
+        ];
+        // 放大到所有食物都使用 High LOD，绘制直径就是实际直径
+        let instances = write_all(&entities, &camera_view(Vector2::ZERO, 1000.0, 10.0));
+
+        assert_eq!(instances.len(), entities.len());
+        for (instance, entity) in instances
+            .as_slice()
+            .chunks(FLOATS_PER_INSTANCE)
+            .zip(&entities)
+        {
+            let diameter = mass_to_diameter(entity.mass);
+            let color = COLOR_PALETTE[entity.entity_id as usize % COLOR_PALETTE.len()];
+            assert!((instance[0] - diameter).abs() < 1e-4);
+            assert_eq!(instance[1], 0.0);
+            assert_eq!(instance[3], entity.position.x);
+            assert_eq!(instance[4], 0.0);
+            assert!((instance[5] - diameter).abs() < 1e-4);
+            assert_eq!(instance[7], entity.position.y);
+            assert_eq!(&instance[8..12], &[color.r, color.g, color.b, color.a]);
+        }
+    }
+
+    #[test]
+    fn food_outside_the_camera_is_culled() {
+        let entities = [
+            food_entity(1, 0.0, 0.0, 2),
+            food_entity(2, 5000.0, 5000.0, 2),
+            food_entity(3, 10.0, 10.0, 2),
+        ];
+        let instances = write_all(&entities, &camera_view(Vector2::ZERO, 500.0, 1.0));
+
+        assert_eq!(instances.len(), 2);
+        let origins: Vec<(f32, f32)> = instances
+            .as_slice()
+            .chunks(FLOATS_PER_INSTANCE)
+            .map(|instance| (instance[3], instance[7]))
+            .collect();
+        assert_eq!(origins, vec![(0.0, 0.0), (10.0, 10.0)]);
+    }
+
+    #[test]
+    fn removing_food_keeps_indices_consistent() {
+        let mut store = FoodStore::default();
+        for entity_id in 1..=4 {
+            store.insert(FoodRenderData::from_entity(&food_entity(entity_id, entity_id as f32, 0.0, 2), 0.0));
+        }
+        store.push_snapshot(4, 1.0, Vector2::new(40.0, 0.0), Vector2::ONE);
+
+        // 删除第一个元素后，最后一个元素被移到它的位置
+        assert!(store.remove(1));
+        assert!(!store.remove(1));
+        assert_eq!(store.len(), 3);
+        assert!(store.push_snapshot(4, 2.0, Vector2::new(50.0, 0.0), Vector2::ONE));
+        assert_eq!(store.animating_count(), 1);
+
+        assert!(store.remove(4));
+        assert_eq!(store.animating_count(), 0);
+        for entity_id in 2..=3 {
+            assert!(store.contains(entity_id));
+            assert!(store.push_snapshot(entity_id, 1.0, Vector2::ZERO, Vector2::ONE));
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn settled_food_leaves_the_animation_list() {
+        let mut store = FoodStore::default();
+        store.insert(FoodRenderData::from_entity(&food_entity(1, 0.0, 0.0, 2), 0.0));
+        store.insert(FoodRenderData::from_entity(&food_entity(2, 0.0, 0.0, 2), 0.0));
+        assert_eq!(store.animating_count(), 0);
+
+        store.push_snapshot(1, 1.0, Vector2::new(10.0, 0.0), Vector2::ONE);
+        assert_eq!(store.animating_count(), 1);
+        assert!(store.animate(0.5));
+        assert_eq!(store.animating_count(), 1);
+
+        // 超过最新快照和外推时间后不再变化
+        store.animate(10.0);
+        assert_eq!(store.animating_count(), 0);
+        assert!(!store.animate(11.0));
+    }
+
+    /// 5000 个食物的微基准，运行：
+    /// `cargo test --release food_store_benchmark -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn food_store_benchmark() {
+        const FOOD_COUNT: u32 = 5000;
+        const FRAMES: u32 = 1000;
+
+        let mut store = FoodStore::default();
+        for entity_id in 0..FOOD_COUNT {
+            let x = (entity_id % 100) as f32 * 10.0;
+            let y = (entity_id / 100) as f32 * 10.0;
+            store.insert(FoodRenderData::from_entity(&food_entity(entity_id, x, y, 3), 0.0));
+        }
+        let view = camera_view(Vector2::new(500.0, 250.0), 1200.0, 1.0);
+        let mut instances = InstanceBuffer::default();
+        store.write_instances(&mut instances, &view);
+        let buffer_ptr = instances.as_slice().as_ptr();
+
+        let start = std::time::Instant::now();
+        for frame in 0..FRAMES {
+            let time = frame as f64 / 60.0;
+            // 每帧有 1% 的食物收到更新
+            for i in 0..FOOD_COUNT / 100 {
+                let entity_id = (frame * 37 + i * 101) % FOOD_COUNT;
+                store.push_snapshot(entity_id, time, Vector2::new(i as f32, frame as f32), Vector2::ONE);
+            }
+            store.animate(time - 0.1);
+            store.write_instances(&mut instances, &view);
+        }
+        let elapsed = start.elapsed();
+
+        // 容量足够后重建实例缓冲区不再分配
+        assert_eq!(instances.as_slice().as_ptr(), buffer_ptr);
+        println!(
+            "{} foods: {:.1} us per frame, {} animating",
+            FOOD_COUNT,
+            elapsed.as_secs_f64() * 1_000_000.0 / FRAMES as f64,
+            store.animating_count()
+        );
+    }
+
+    #[test]
+    fn distant_or_tiny_food_uses_lower_lod() {
+        assert_eq!(get_food_lod(0.0, 40.0), FoodLOD::High);
+        assert_eq!(get_food_lod(0.0, 10.0), FoodLOD::Medium);
+        assert_eq!(get_food_lod(600.0, 40.0), FoodLOD::Medium);
+        assert_eq!(get_food_lod(900.0, 40.0), FoodLOD::Low);
+        assert_eq!(get_food_lod(0.0, 2.0), FoodLOD::Low);
+    }
+}
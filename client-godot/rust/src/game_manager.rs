@@ -1,23 +1,112 @@
 use super::*;
 use crate::global_state::*;
 use crate::global_state::food_batch_renderer;
+use crate::global_state::circle_batch_renderer;
+use crate::global_state::death_screen;
+use crate::global_state::main_menu;
 use crate::camera_controller::WORLD_SIZE;
-use godot::classes::Engine;
+use godot::classes::{AcceptDialog, Engine, Os, Time};
 use spacetimedb_sdk::*;
+use spacetimedb_sdk::SubscriptionHandle as _;
 
 #[derive(GodotClass)]
 #[class(init, base=Node)]
 pub struct GameManager {
     base: Base<Node>,
+
+    /// 渲染延迟（毫秒），实体在两个服务器快照之间插值显示
+    #[export(range = (0.0, 500.0, 1.0))]
+    #[init(val = 100.0)]
+    render_delay_ms: f32,
+
+    /// 服务器地址，可被环境变量 BLACKHOLIO_SERVER_URL 或命令行 --server-url 覆盖
+    #[export]
+    #[init(val = GString::from("http://127.0.0.1:3000"))]
+    server_url: GString,
+
+    /// 模块名，可被环境变量 BLACKHOLIO_MODULE 或命令行 --module 覆盖
+    #[export]
+    #[init(val = GString::from("blackholio"))]
+    module_name: GString,
+
+    /// 凭据配置名，可被环境变量 BLACKHOLIO_PROFILE 或命令行 --profile 覆盖
+    #[export]
+    credentials_profile: GString,
+
+    /// 访客模式，可被环境变量 BLACKHOLIO_GUEST 或命令行 --guest 开启
+    #[export]
+    guest: bool,
 }
 
 // 全局状态现在通过 global_state 模块管理
 // 不再需要 unsafe 静态变量
 
+/// SDK 回调中产生、由 GameManager 以信号发出的事件
+#[derive(Clone, Debug, PartialEq)]
+pub enum GameEvent {
+    Connected { identity: String },
+    Disconnected { reason: String },
+    SubscriptionApplied,
+    PlayerJoined { player_id: u32, name: String },
+    PlayerLeft { player_id: u32, name: String },
+    LocalPlayerDied { player_id: u32 },
+    ReducerError { reducer: String, message: String },
+}
+
 impl GameManager {
-    const SERVER_URL: &'static str = "http://127.0.0.1:3000";
-    const MODULE_NAME: &'static str = "blackholio";
+    /// 合并导出属性、环境变量和命令行用户参数
+    fn resolve_settings(&self) -> Result<ConnectionSettings, String> {
+        let mut settings = ConnectionSettings {
+            server_url: self.server_url.to_string(),
+            module_name: self.module_name.to_string(),
+            profile: self.credentials_profile.to_string(),
+            guest: self.guest,
+        };
+        settings.apply_env(|name| std::env::var(name).ok());
+
+        let args: Vec<String> = Os::singleton()
+            .get_cmdline_user_args()
+            .as_slice()
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        settings.apply_args(&args)?;
+        settings.validate()?;
+        Ok(settings)
+    }
     
+    /// 发出回调中排队的事件
+    ///
+    /// 在 frame_tick 之后统一发出，GDScript 的处理函数不会在 SDK 回调中途运行
+    fn emit_events(&mut self) {
+        for event in events::take() {
+            match event {
+                GameEvent::Connected { identity } => {
+                    self.signals().on_connected().emit(identity.as_str())
+                }
+                GameEvent::Disconnected { reason } => {
+                    self.signals().on_disconnected().emit(reason.as_str())
+                }
+                GameEvent::SubscriptionApplied => self.signals().on_subscription_applied().emit(),
+                GameEvent::PlayerJoined { player_id, name } => self
+                    .signals()
+                    .on_player_joined()
+                    .emit(player_id, name.as_str()),
+                GameEvent::PlayerLeft { player_id, name } => self
+                    .signals()
+                    .on_player_left()
+                    .emit(player_id, name.as_str()),
+                GameEvent::LocalPlayerDied { player_id } => {
+                    self.signals().on_local_player_died().emit(player_id)
+                }
+                GameEvent::ReducerError { reducer, message } => self
+                    .signals()
+                    .on_reducer_error()
+                    .emit(reducer.as_str(), message.as_str()),
+            }
+        }
+    }
+
     /// 初始化食物批量渲染器
     fn setup_food_batch_renderer(&mut self) {
         // 创建食物批量渲染器实例
@@ -45,15 +134,95 @@ impl GameManager {
         
         godot_print!("FoodBatchRenderer setup completed");
     }
+
+    /// 初始化圆形批量渲染器
+    fn setup_circle_batch_renderer(&mut self) {
+        let mut circle_renderer = CircleBatchRenderer::new_alloc();
+        circle_renderer.set_name("CircleBatchRenderer");
+
+        // 在食物之后加入场景树，圆形绘制在食物之上
+        if let Some(mut root) = get_root() {
+            root.call_deferred("add_child", &[circle_renderer.to_variant()]);
+        } else {
+            godot_error!("Failed to get root node for CircleBatchRenderer");
+        }
+
+        circle_batch_renderer::set_instance(circle_renderer);
+    }
+
+    /// 初始化排行榜 HUD
+    fn setup_leaderboard_hud(&mut self) {
+        let mut hud = LeaderboardHud::new_alloc();
+        hud.set_name("LeaderboardHud");
+
+        if let Some(mut root) = get_root() {
+            root.call_deferred("add_child", &[hud.to_variant()]);
+        } else {
+            godot_error!("Failed to get root node for LeaderboardHud");
+        }
+    }
+
+    /// 初始化聊天面板
+    fn setup_chat_panel(&mut self) {
+        let mut panel = ChatPanel::new_alloc();
+        panel.set_name("ChatPanel");
+
+        if let Some(mut root) = get_root() {
+            root.call_deferred("add_child", &[panel.to_variant()]);
+        } else {
+            godot_error!("Failed to get root node for ChatPanel");
+        }
+    }
+
+    /// 初始化死亡界面
+    fn setup_death_screen(&mut self) {
+        let mut screen = DeathScreen::new_alloc();
+        screen.set_name("DeathScreen");
+
+        if let Some(mut root) = get_root() {
+            root.call_deferred("add_child", &[screen.to_variant()]);
+        } else {
+            godot_error!("Failed to get root node for DeathScreen");
+        }
+
+        death_screen::set_instance(screen);
+    }
+
+    /// 初始化主菜单
+    fn setup_main_menu(&mut self) {
+        let mut menu = MainMenu::new_alloc();
+        menu.set_name("MainMenu");
+
+        if let Some(mut root) = get_root() {
+            root.call_deferred("add_child", &[menu.to_variant()]);
+        } else {
+            godot_error!("Failed to get root node for MainMenu");
+        }
+
+        main_menu::set_instance(menu);
+    }
 }
 
 #[godot_api]
 impl INode for GameManager {
     fn process(&mut self, _delta: f64) {
+        // 每帧同步，运行时在检查器中修改也能立即生效
+        interpolation::set_render_delay(self.render_delay_ms as f64 / 1000.0);
+
         if let Some(conn) = connection::get_connection() {
-            conn.frame_tick()
-                .expect("Failed to process WebSocket messages");
+            if let Err(e) = conn.frame_tick() {
+                godot_error!("Failed to process WebSocket messages: {}", e);
+                schedule_reconnect();
+            }
+        }
+
+        // 到了重连时间则重新建立连接
+        let now = Time::singleton().get_ticks_msec() as f64 / 1000.0;
+        if connection::with_backoff(|backoff| backoff.take_due(now)) {
+            connect();
         }
+
+        self.emit_events();
     }
 
     fn exit_tree(&mut self) {
@@ -66,105 +235,454 @@ impl INode for GameManager {
         // 初始化食物批量渲染器
         self.setup_food_batch_renderer();
 
-        let builder = DbConnection::builder()
-            .on_connect(handle_connect)
-            .on_connect_error(handle_connect_error)
-            .on_disconnect(handle_disconnect)
-            .with_token(creds_store().load().expect("Failed to load credentials"))
-            .with_uri(Self::SERVER_URL)
-            .with_module_name(Self::MODULE_NAME);
-        let conn = builder.build().unwrap();
-        connection::set_connection(conn);
+        // 初始化圆形批量渲染器
+        self.setup_circle_batch_renderer();
+
+        // 初始化排行榜 HUD
+        self.setup_leaderboard_hud();
+
+        // 初始化聊天面板
+        self.setup_chat_panel();
+
+        // 初始化死亡界面
+        self.setup_death_screen();
+
+        // 初始化主菜单
+        self.setup_main_menu();
+
+        match self.resolve_settings() {
+            Ok(settings) => {
+                godot_print!("Connection settings: {:?}", settings);
+                connection::set_settings(settings);
+                connect();
+            }
+            Err(e) => {
+                connection::set_status(ConnectionStatus::Failed(e.clone()));
+                show_error(&format!("Invalid connection settings: {}", e));
+            }
+        }
+    }
+}
+
+/// 建立新的连接，失败时安排重连
+fn connect() {
+    let settings = connection::settings();
+    godot_print!("Connecting to SpacetimeDB at {}...", settings.server_url);
+    connection::set_status(ConnectionStatus::Connecting);
+    let token = match creds_store(&settings) {
+        Some(store) => store.load().unwrap_or_else(|e| {
+            godot_error!("Failed to load credentials: {:?}", e);
+            None
+        }),
+        None => connection::guest_token(),
+    };
+
+    let builder = DbConnection::builder()
+        .on_connect(handle_connect)
+        .on_connect_error(handle_connect_error)
+        .on_disconnect(handle_disconnect)
+        .with_token(token)
+        .with_uri(settings.server_url)
+        .with_module_name(settings.module_name);
+    match builder.build() {
+        Ok(conn) => connection::set_connection(conn),
+        Err(e) => {
+            godot_error!("Failed to connect to SpacetimeDB: {}", e);
+            schedule_reconnect();
+        }
     }
 }
 
-fn creds_store() -> credentials::File {
-    credentials::File::new("blackholio")
+/// 丢弃当前连接和场景状态，按指数退避安排重连
+fn schedule_reconnect() {
+    // 同一次断开已经安排过重连
+    if connection::with_backoff(|backoff| backoff.is_pending()) {
+        return;
+    }
+    connection::clear_connection();
+    reset_scene_state();
+
+    let now = Time::singleton().get_ticks_msec() as f64 / 1000.0;
+    if let Some(delay) = connection::with_backoff(|backoff| backoff.schedule(now)) {
+        godot_print!("Reconnecting in {:.0} seconds", delay);
+        connection::set_status(ConnectionStatus::Reconnecting { delay_sec: delay });
+    }
+}
+
+/// 移除由旧连接创建的节点，新订阅的插入回调会重新创建它们
+fn reset_scene_state() {
+    // 先清除本地玩家，移除圆圈时不会当作死亡
+    players::clear_local_player();
+    for mut player_controller in players::remove_all() {
+        player_controller.queue_free();
+    }
+
+    if let Some(mut batch_renderer) = food_batch_renderer::get_instance() {
+        batch_renderer.bind_mut().clear_food();
+    }
+    if let Some(mut batch_renderer) = circle_batch_renderer::get_instance() {
+        batch_renderer.bind_mut().clear_circles();
+    }
+    movement::clear_rules();
+    input_history::clear();
+    hud::mark_chat_dirty();
+    hud::mark_leaderboard_dirty();
+
+    // 服务器在断开时移除了本地玩家的圆圈，需要重新进入游戏
+    if let Some(mut screen) = death_screen::get_instance() {
+        screen.set_visible(false);
+    }
+    if let Some(mut menu) = main_menu::get_instance() {
+        menu.bind_mut().show_menu();
+    }
+}
+
+/// 访客模式不使用凭据文件
+fn creds_store(settings: &ConnectionSettings) -> Option<credentials::File> {
+    if settings.guest {
+        None
+    } else {
+        Some(credentials::File::new(settings.credentials_key()))
+    }
 }
 
 fn handle_connect(_ctx: &DbConnection, identity: Identity, token: &str) {
     godot_print!("Connected to SpacetimeDB");
-    if let Err(e) = creds_store().save(token) {
-        godot_error!("Failed to save credentials: {:?}", e);
+    connection::set_status(ConnectionStatus::Connected);
+    connection::with_backoff(|backoff| backoff.on_connected());
+    match creds_store(&connection::settings()) {
+        Some(store) => {
+            if let Err(e) = store.save(token) {
+                godot_error!("Failed to save credentials: {:?}", e);
+            }
+        }
+        None => connection::set_guest_token(token.to_string()),
     }
 
     identity::set_local_identity(identity);
+    events::push(GameEvent::Connected {
+        identity: identity.to_hex().to_string(),
+    });
 
     if let Some(conn) = connection::get_connection() {
         conn.db.circle().on_insert(circle_on_insert);
         conn.db.entity().on_update(entity_on_update);
         conn.db.entity().on_delete(entity_on_delete);
+        conn.db.config().on_insert(config_on_insert);
+        conn.db.config().on_update(config_on_update);
         conn.db.player().on_insert(player_on_insert);
+        conn.db.player().on_update(player_on_update);
         conn.db.player().on_delete(player_on_delete);
+        conn.db.leaderboard().on_insert(leaderboard_on_insert);
+        conn.db.leaderboard().on_update(leaderboard_on_update);
+        conn.db.leaderboard().on_delete(leaderboard_on_delete);
+        conn.db.chat_message().on_insert(chat_message_on_insert);
+        conn.db.chat_message().on_delete(chat_message_on_delete);
         conn.db.food().on_insert(food_on_insert);
-
-        // GAME_MANAGER_INSTANCE.with(|x| x.get().unwrap().signals().on_connected().emit());
+        conn.db.world_tick().on_insert(world_tick_on_insert);
+        conn.db.world_tick().on_update(world_tick_on_update);
+        conn.db.rate_limit_notice().on_insert(rate_limit_notice_on_insert);
+        conn.db.rate_limit_notice().on_update(rate_limit_notice_on_update);
+        conn.reducers.on_enter_game(|ctx, _name| enter_game_result(ctx));
+        conn.reducers.on_respawn(respawn_result);
+        conn.reducers.on_send_chat(|ctx, _text| show_reducer_error(ctx, "send_chat"));
+        // 分裂和输入由按键和每帧发送，失败时只发出信号，不弹出对话框
+        conn.reducers.on_player_split(|ctx| {
+            report_reducer_error(ctx, "player_split");
+        });
+        conn.reducers.on_update_player_input(|ctx, _direction| {
+            update_player_input_result(ctx);
+        });
 
         conn.subscription_builder()
             .on_applied(handle_subscription_applied)
             .on_error(handle_subscription_error)
-            .subscribe_to_all_tables();
+            .subscribe(GLOBAL_QUERIES);
     }
 }
 
 fn handle_connect_error(_ctx: &ErrorContext, error: Error) {
     godot_error!("Failed to connect to SpacetimeDB: {}", error);
+    events::push(GameEvent::Disconnected {
+        reason: error.to_string(),
+    });
+    schedule_reconnect();
 }
 
 fn handle_disconnect(_ctx: &ErrorContext, error: Option<Error>) {
     println!("Disconnected from SpacetimeDB");
-    if let Some(error) = error {
+    if let Some(error) = &error {
         godot_error!("{}", error);
     }
+    events::push(GameEvent::Disconnected {
+        reason: error.map(|x| x.to_string()).unwrap_or_default(),
+    });
+    schedule_reconnect();
 }
 
 fn handle_subscription_applied(ctx: &SubscriptionEventContext) {
     godot_print!("Subscription applied!");
 
-    if let Some(conn) = connection::get_connection() {
-        let world_size = conn.db.config().id().find(&0).unwrap().world_size;
-        setup_arena(world_size as u32);
+    sync_arena_subscription(ctx);
+
+    // 由主菜单的 Play 按钮进入游戏
+    connection::set_status(ConnectionStatus::Ready);
+    events::push(GameEvent::SubscriptionApplied);
+}
+
+/// 与竞技场无关的表，连接后立即订阅
+const GLOBAL_QUERIES: [&str; 10] = [
+    "SELECT * FROM arena",
+    "SELECT * FROM world_tick",
+    "SELECT * FROM config",
+    "SELECT * FROM player",
+    "SELECT * FROM bot",
+    "SELECT * FROM leaderboard",
+    "SELECT * FROM chat_message",
+    "SELECT * FROM kill_event",
+    "SELECT * FROM player_stats",
+    "SELECT * FROM rate_limit_notice",
+];
+
+/// 只订阅一个竞技场的实体、圆形和食物
+fn arena_queries(arena_id: u32) -> [String; 3] {
+    [
+        format!("SELECT * FROM entity WHERE arena_id = {arena_id}"),
+        format!(
+            "SELECT circle.* FROM circle JOIN entity ON circle.entity_id = entity.entity_id WHERE entity.arena_id = {arena_id}"
+        ),
+        format!(
+            "SELECT food.* FROM food JOIN entity ON food.entity_id = entity.entity_id WHERE entity.arena_id = {arena_id}"
+        ),
+    ]
+}
+
+/// 让实体订阅跟随本地玩家所在的竞技场
+///
+/// 竞技场变化时取消旧的订阅，旧竞技场的行从缓存中删除时会触发删除回调，
+/// 新订阅的插入回调再创建新竞技场的圆形和食物
+fn sync_arena_subscription(ctx: &impl RemoteDbContext) {
+    let Some(local_identity) = identity::get_local_identity() else {
+        return;
+    };
+    let Some(player) = ctx.db().player().identity().find(&local_identity) else {
+        return;
     };
+    if connection::arena_subscription_id() == Some(player.arena_id) {
+        return;
+    }
+
+    if let Some(handle) = connection::take_arena_subscription() {
+        if let Err(e) = handle.unsubscribe() {
+            godot_error!("Failed to unsubscribe from the old arena: {}", e);
+        }
+    }
+    match ctx.db().arena().arena_id().find(&player.arena_id) {
+        Some(arena) => setup_arena(arena.world_size as u32),
+        None => godot_error!("Arena {} not found in subscription", player.arena_id),
+    }
 
-    ctx.reducers.enter_game("3Blave".to_string()).unwrap();
+    let handle = ctx
+        .subscription_builder()
+        .on_error(handle_subscription_error)
+        .subscribe(arena_queries(player.arena_id));
+    connection::set_arena_subscription(player.arena_id, handle);
+}
+
+/// 在屏幕上显示错误信息
+pub fn show_error(message: &str) {
+    godot_error!("{}", message);
+
+    let Some(mut root) = get_root() else {
+        return;
+    };
+    let mut dialog = AcceptDialog::new_alloc();
+    dialog.set_title("Error");
+    dialog.set_text(message);
+    dialog.connect("confirmed", &dialog.callable("queue_free"));
+    dialog.connect("canceled", &dialog.callable("queue_free"));
+    root.add_child(&dialog);
+    dialog.popup_centered();
+}
+
+/// 显示服务器拒绝 reducer 调用的原因
+fn show_reducer_error(ctx: &ReducerEventContext, reducer: &str) {
+    if let Some(message) = report_reducer_error(ctx, reducer) {
+        show_error(&message);
+    }
+}
+
+/// 本地玩家的 reducer 调用被拒绝时发出 `on_reducer_error`，返回拒绝原因
+fn report_reducer_error(ctx: &ReducerEventContext, reducer: &str) -> Option<String> {
+    if Some(ctx.event.caller_identity) != identity::get_local_identity() {
+        return None;
+    }
+    let Status::Failed(message) = &ctx.event.status else {
+        return None;
+    };
+    godot_warn!("{} failed: {}", reducer, message);
+    push_reducer_error(reducer, message);
+    Some(message.to_string())
+}
+
+/// 本地玩家的输入被服务器处理，用于测量往返时间
+fn update_player_input_result(ctx: &ReducerEventContext) {
+    if Some(ctx.event.caller_identity) != identity::get_local_identity() {
+        return;
+    }
+    input_history::acknowledge();
+    report_reducer_error(ctx, "update_player_input");
+}
+
+fn push_reducer_error(reducer: &str, message: &str) {
+    events::push(GameEvent::ReducerError {
+        reducer: reducer.to_string(),
+        message: message.to_string(),
+    });
+}
+
+/// 把本地玩家的 enter_game 结果交给主菜单，由它显示错误或隐藏自己
+fn enter_game_result(ctx: &ReducerEventContext) {
+    if Some(ctx.event.caller_identity) != identity::get_local_identity() {
+        return;
+    }
+    let result = match &ctx.event.status {
+        Status::Committed => Ok(()),
+        Status::Failed(message) => Err(message.to_string()),
+        _ => Err("Failed to enter game".to_string()),
+    };
+    if let Err(message) = &result {
+        push_reducer_error("enter_game", message);
+    }
+    match main_menu::get_instance() {
+        Some(mut menu) => menu.bind_mut().on_enter_game_result(result),
+        None => {
+            if let Err(message) = &result {
+                show_error(message);
+            }
+        }
+    }
+}
+
+/// 重生失败时让死亡界面保持打开，显示原因
+fn respawn_result(ctx: &ReducerEventContext) {
+    if Some(ctx.event.caller_identity) != identity::get_local_identity() {
+        return;
+    }
+    if let Status::Failed(message) = &ctx.event.status {
+        push_reducer_error("respawn", message);
+        match death_screen::get_instance() {
+            Some(mut screen) => screen.bind_mut().on_respawn_failed(message),
+            None => show_error(message),
+        }
+    }
+}
+
+/// 手动操作被限流时 reducer 仍然成功（失败会回滚服务器的拒绝计数），
+/// 服务器改为更新本地身份的 rate_limit_notice 行
+fn rate_limit_notice_on_insert(ctx: &EventContext, notice: &RateLimitNotice) {
+    // 订阅时收到的是以前的通知
+    if !matches!(ctx.event, Event::Reducer(_))
+        || Some(notice.identity) != identity::get_local_identity()
+    {
+        return;
+    }
+    let message = "Too many requests, try again in a moment";
+    let reducer = match notice.action {
+        RateLimitedAction::Respawn => "respawn",
+        RateLimitedAction::PlayerSplit => "player_split",
+        RateLimitedAction::Chat => "send_chat",
+        RateLimitedAction::PlayerInput => return,
+    };
+    godot_warn!("{} failed: {}", reducer, message);
+    push_reducer_error(reducer, message);
+
+    match notice.action {
+        RateLimitedAction::Respawn => match death_screen::get_instance() {
+            Some(mut screen) => screen.bind_mut().on_respawn_failed(message),
+            None => show_error(message),
+        },
+        RateLimitedAction::Chat => show_error(message),
+        // 分裂由按键发送，只发出信号
+        _ => {}
+    }
+}
+
+fn rate_limit_notice_on_update(ctx: &EventContext, _old: &RateLimitNotice, new: &RateLimitNotice) {
+    rate_limit_notice_on_insert(ctx, new);
 }
 
 fn handle_subscription_error(_ctx: &ErrorContext, error: Error) {
     godot_error!("Subscription error: {}", error);
 }
 
+/// 主动断开连接，之后不再重连
 fn disconnect() {
+    connection::with_backoff(|backoff| backoff.stop());
     if let Some(conn) = connection::get_connection() {
-        conn.disconnect().unwrap();
+        if let Err(e) = conn.disconnect() {
+            godot_error!("Failed to disconnect: {}", e);
+        }
     };
 
     connection::clear_connection();
 }
 
+/// 获取本地玩家所在的竞技场
+pub fn local_arena_id() -> Option<u32> {
+    let conn = connection::get_connection()?;
+    let local_identity = identity::get_local_identity()?;
+    conn.db.player().identity().find(&local_identity).map(|x| x.arena_id)
+}
+
+/// 检查实体是否在本地玩家所在的竞技场中，其他竞技场的实体不渲染
+fn is_in_local_arena(ctx: &EventContext, entity_id: u32) -> bool {
+    match ctx.db.entity().entity_id().find(&entity_id) {
+        Some(entity) => Some(entity.arena_id) == local_arena_id(),
+        None => false,
+    }
+}
+
 fn setup_arena(world_size: u32) {
     WORLD_SIZE.store(world_size, std::sync::atomic::Ordering::Relaxed);
     let world_size = world_size as f32;
 
+    // 重连后会再次调用，先移除旧的墙
+    let Some(mut root) = get_root() else {
+        return;
+    };
+    if let Some(mut walls) = root.get_node_or_null("Walls") {
+        root.remove_child(&walls);
+        walls.queue_free();
+    }
+    let mut walls = Node2D::new_alloc();
+    walls.set_name("Walls");
+    root.add_child(&walls);
+
     let thickness = 2.0;
     create_border_cube(
+        &mut walls,
         Vector2::new(world_size / 2.0, world_size + thickness / 2.0),
         Vector2::new(world_size + thickness * 2.0, thickness),
     );
     create_border_cube(
+        &mut walls,
         Vector2::new(world_size / 2.0, -thickness / 2.0),
         Vector2::new(world_size + thickness * 2.0, thickness),
     );
     create_border_cube(
+        &mut walls,
         Vector2::new(world_size + thickness / 2.0, world_size / 2.0),
         Vector2::new(thickness, world_size + thickness * 2.0),
     );
     create_border_cube(
+        &mut walls,
         Vector2::new(-thickness / 2.0, world_size / 2.0),
         Vector2::new(thickness, world_size + thickness * 2.0),
     );
 }
 
-fn create_border_cube(pos: Vector2, size: Vector2) {
+fn create_border_cube(walls: &mut Gd<Node2D>, pos: Vector2, size: Vector2) {
     let mut wall = load::<PackedScene>("res://prefabs/wall_prefab.tscn")
         .instantiate()
         .unwrap()
@@ -173,16 +691,29 @@ fn create_border_cube(pos: Vector2, size: Vector2) {
     wall.set_position(pos);
     wall.set_scale(size * 0.01);
 
-    get_root().unwrap().add_child(&wall);
+    walls.add_child(&wall);
 }
 
-fn circle_on_insert(_ctx: &EventContext, circle: &Circle) {
+fn circle_on_insert(ctx: &EventContext, circle: &Circle) {
     godot_print!("Circle inserted!");
-    let player = get_or_create_player(circle.player_id);
-    if let Some(player) = player {
-        let entity = spawn_circle(circle.clone(), player);
-        entities::insert_entity(circle.entity_id, EntityController::Circle(entity));
+    if !is_in_local_arena(ctx, circle.entity_id)
+        || circle_batch_renderer::is_circle_entity(circle.entity_id)
+    {
+        return;
     }
+
+    let (Some(mut player), Some(mut batch_renderer)) = (
+        get_or_create_player(circle.player_id),
+        circle_batch_renderer::get_instance(),
+    ) else {
+        return;
+    };
+    let (name, is_local_player) = {
+        let player = player.bind();
+        (player.username(), player.is_local_player())
+    };
+    batch_renderer.bind_mut().add_circle(circle, name, is_local_player);
+    player.bind_mut().on_circle_spawned(circle.entity_id);
 }
 
 fn entity_on_update(_ctx: &EventContext, _old_entity: &Entity, new_entity: &Entity) {
@@ -196,10 +727,9 @@ fn entity_on_update(_ctx: &EventContext, _old_entity: &Entity, new_entity: &Enti
         return;
     }
 
-    // 其他实体的处理保持不变
-    entities::update_entity(new_entity.entity_id, |entity_controller| {
-        entity_controller.on_entity_updated(new_entity);
-    });
+    if let Some(mut batch_renderer) = circle_batch_renderer::get_instance() {
+        batch_renderer.bind_mut().update_circle_entity(new_entity);
+    }
 }
 
 fn entity_on_delete(_ctx: &EventContext, entity: &Entity) {
@@ -213,27 +743,101 @@ fn entity_on_delete(_ctx: &EventContext, entity: &Entity) {
         return;
     }
     
-    // 其他实体的处理保持不变
-    if let Some(mut entity_controller) = entities::remove_entity(entity.entity_id) {
-        entity_controller.on_delete();
-    };
+    // 圆形从批量渲染器移除后通知所属玩家，本地玩家失去所有圆形时显示死亡界面
+    let player_id = circle_batch_renderer::get_instance()
+        .and_then(|mut batch_renderer| batch_renderer.bind_mut().remove_circle(entity.entity_id));
+    if let Some(mut player_controller) = player_id.and_then(players::get_player) {
+        player_controller.bind_mut().on_circle_deleted(entity.entity_id);
+    }
 }
 
-fn player_on_insert(_ctx: &EventContext, player: &Player) {
+/// 所有竞技场共用服务器时钟，任何刻度都可用于校准
+fn world_tick_on_insert(_ctx: &EventContext, world_tick: &WorldTick) {
+    interpolation::observe_server_time(timestamp_to_secs(world_tick.timestamp));
+}
+
+fn world_tick_on_update(ctx: &EventContext, _old: &WorldTick, new: &WorldTick) {
+    world_tick_on_insert(ctx, new);
+}
+
+/// 预测使用的移动规则只在配置变化时重建
+fn config_on_insert(_ctx: &EventContext, config: &Config) {
+    movement::set_rules(MovementRules::from_config(config));
+}
+
+fn config_on_update(ctx: &EventContext, _old: &Config, new: &Config) {
+    config_on_insert(ctx, new);
+}
+
+fn player_on_insert(ctx: &EventContext, player: &Player) {
     godot_print!("Player inserted!");
     get_or_create_player(player.player_id);
+    hud::mark_chat_dirty();
+    if Some(player.identity) == identity::get_local_identity() {
+        hud::mark_leaderboard_dirty();
+        sync_arena_subscription(ctx);
+    }
+    events::push(GameEvent::PlayerJoined {
+        player_id: player.player_id,
+        name: player.name.clone(),
+    });
+}
+
+/// 名字可能变化，刷新聊天和圆形上的名字；本地玩家通过 join_arena 换到其他竞技场时重新订阅
+fn player_on_update(ctx: &EventContext, old: &Player, new: &Player) {
+    hud::mark_chat_dirty();
+    if old.name != new.name {
+        if let Some(mut batch_renderer) = circle_batch_renderer::get_instance() {
+            batch_renderer.bind_mut().rename_player(new.player_id, &new.name);
+        }
+    }
+    if old.arena_id != new.arena_id && Some(new.identity) == identity::get_local_identity() {
+        godot_print!("Moved to arena {}", new.arena_id);
+        hud::mark_leaderboard_dirty();
+        sync_arena_subscription(ctx);
+    }
 }
 
 fn player_on_delete(_ctx: &EventContext, player: &Player) {
     godot_print!("Player deleted!");
+    hud::mark_chat_dirty();
 
     if let Some(mut player_controller) = players::remove_player(player.player_id) {
         player_controller.bind_mut().base_mut().queue_free();
     };
+    events::push(GameEvent::PlayerLeft {
+        player_id: player.player_id,
+        name: player.name.clone(),
+    });
+}
+
+/// 排行榜 HUD 在下一帧重建文本
+fn leaderboard_on_insert(_ctx: &EventContext, _entry: &LeaderboardEntry) {
+    hud::mark_leaderboard_dirty();
+}
+
+fn leaderboard_on_update(_ctx: &EventContext, _old: &LeaderboardEntry, _new: &LeaderboardEntry) {
+    hud::mark_leaderboard_dirty();
+}
+
+fn leaderboard_on_delete(_ctx: &EventContext, _entry: &LeaderboardEntry) {
+    hud::mark_leaderboard_dirty();
+}
+
+/// 聊天面板在下一帧重建文本
+fn chat_message_on_insert(_ctx: &EventContext, _message: &ChatMessage) {
+    hud::mark_chat_dirty();
+}
+
+fn chat_message_on_delete(_ctx: &EventContext, _message: &ChatMessage) {
+    hud::mark_chat_dirty();
 }
 
-fn food_on_insert(_ctx: &EventContext, food: &Food) {
+fn food_on_insert(ctx: &EventContext, food: &Food) {
     godot_print!("Food inserted! entity_id: {}", food.entity_id);
+    if !is_in_local_arena(ctx, food.entity_id) {
+        return;
+    }
     
     // 不再创建独立节点，而是添加到批量渲染器
     match food_batch_renderer::get_instance() {
@@ -282,9 +886,31 @@ fn get_or_create_player(player_id: u32) -> Option<Gd<PlayerController>> {
 
 #[godot_api]
 impl GameManager {
+    /// 连接成功，参数为本地身份的十六进制字符串
+    #[signal]
+    fn on_connected(identity: GString);
+
+    /// 连接断开或无法建立，之后会自动重连；正常断开时原因为空
     #[signal]
-    fn on_connected();
+    fn on_disconnected(reason: GString);
 
+    /// 订阅数据已就绪，可以进入游戏
     #[signal]
     fn on_subscription_applied();
+
+    /// 玩家行插入，订阅就绪时已有的玩家也会触发
+    #[signal]
+    fn on_player_joined(player_id: u32, name: GString);
+
+    /// 玩家断开连接
+    #[signal]
+    fn on_player_left(player_id: u32, name: GString);
+
+    /// 本地玩家失去了所有圆圈
+    #[signal]
+    fn on_local_player_died(player_id: u32);
+
+    /// 服务器拒绝了本地玩家的 reducer 调用
+    #[signal]
+    fn on_reducer_error(reducer: GString, message: GString);
 }
@@ -1,113 +1,136 @@
 /// 全局状态管理模块
 /// 
 /// 此模块提供线程安全的全局状态管理，替代不安全的静态变量
-/// 由于 Godot 对象不是线程安全的，我们使用 thread_local 存储和 OnceCell 进行单线程使用
+/// 由于 Godot 对象不是线程安全的，我们使用 thread_local 存储，连接和身份使用 RwLock 以便重连时替换
 
 use std::collections::HashMap;
-use std::sync::{Arc, OnceLock};
-use std::cell::RefCell;
-use crate::{DbConnection, EntityController, PlayerController, PrefabManager, FoodBatchRenderer};
+use std::sync::{Arc, RwLock};
+use std::cell::{Cell, RefCell};
+use crate::{DbConnection, SubscriptionHandle, InputHistory, MovementRules, DeathScreen, MainMenu, PlayerController, PrefabManager, FoodBatchRenderer, CircleBatchRenderer, ReconnectBackoff, ConnectionSettings, ConnectionStatus, GameEvent};
 use spacetimedb_sdk::Identity;
 use godot::prelude::*;
 
 /// 连接管理
-/// 使用 Arc 包装以支持跨函数共享
-static CONNECTION: OnceLock<Arc<DbConnection>> = OnceLock::new();
+/// 使用 Arc 包装以支持跨函数共享，断线重连时整体替换
+static CONNECTION: RwLock<Option<Arc<DbConnection>>> = RwLock::new(None);
 
 /// 本地身份标识
-static LOCAL_IDENTITY: OnceLock<Identity> = OnceLock::new();
+static LOCAL_IDENTITY: RwLock<Option<Identity>> = RwLock::new(None);
 
 // 游戏实体存储 - 使用 thread_local 因为 Godot 对象不是线程安全的
 thread_local! {
-    static ENTITIES: RefCell<HashMap<u32, EntityController>> = RefCell::new(HashMap::new());
     static PLAYERS: RefCell<HashMap<u32, Gd<PlayerController>>> = RefCell::new(HashMap::new());
     static LOCAL_PLAYER: RefCell<Option<Arc<Gd<PlayerController>>>> = RefCell::new(None);
     static PREFAB_MANAGER: RefCell<Option<Gd<PrefabManager>>> = RefCell::new(None);
     static FOOD_BATCH_RENDERER: RefCell<Option<Gd<FoodBatchRenderer>>> = RefCell::new(None);
+    static CIRCLE_BATCH_RENDERER: RefCell<Option<Gd<CircleBatchRenderer>>> = RefCell::new(None);
+    static DEATH_SCREEN: RefCell<Option<Gd<DeathScreen>>> = RefCell::new(None);
+    static MAIN_MENU: RefCell<Option<Gd<MainMenu>>> = RefCell::new(None);
+    static RECONNECT_BACKOFF: RefCell<ReconnectBackoff> = RefCell::new(ReconnectBackoff::default());
+    static CONNECTION_STATUS: RefCell<ConnectionStatus> = RefCell::new(ConnectionStatus::Connecting);
+    static CONNECTION_SETTINGS: RefCell<ConnectionSettings> = RefCell::new(ConnectionSettings::default());
+    static ARENA_SUBSCRIPTION: RefCell<Option<(u32, SubscriptionHandle)>> = RefCell::new(None);
+    static GUEST_TOKEN: RefCell<Option<String>> = RefCell::new(None);
+    static PENDING_EVENTS: RefCell<Vec<GameEvent>> = RefCell::new(Vec::new());
+    static RENDER_DELAY_SEC: Cell<f64> = const { Cell::new(0.1) };
+    static SERVER_CLOCK_OFFSET: Cell<Option<f64>> = const { Cell::new(None) };
+    static MOVEMENT_RULES: RefCell<Option<MovementRules>> = const { RefCell::new(None) };
+    static INPUT_HISTORY: RefCell<InputHistory> = RefCell::new(InputHistory::default());
+    static CHAT_DIRTY: Cell<bool> = const { Cell::new(true) };
+    static LEADERBOARD_DIRTY: Cell<bool> = const { Cell::new(true) };
 }
 
 /// 连接管理函数
 pub mod connection {
     use super::*;
 
-    /// 设置数据库连接
+    /// 设置数据库连接，替换之前的连接
     pub fn set_connection(conn: DbConnection) {
-        let _ = CONNECTION.set(Arc::new(conn));
+        *CONNECTION.write().unwrap() = Some(Arc::new(conn));
     }
 
     /// 获取数据库连接
     pub fn get_connection() -> Option<Arc<DbConnection>> {
-        CONNECTION.get().cloned()
+        CONNECTION.read().unwrap().clone()
     }
 
     /// 检查是否已连接
     pub fn is_connected() -> bool {
-        CONNECTION.get().is_some()
+        CONNECTION.read().unwrap().is_some()
     }
 
-    /// 清除连接
+    /// 清除连接，客户端缓存随最后一个引用一起释放
     pub fn clear_connection() {
-        // OnceCell 不支持清除，但我们可以通过重新创建来实现类似效果
-        // 在实际应用中，连接断开后通常会重新创建连接
+        *CONNECTION.write().unwrap() = None;
+        ARENA_SUBSCRIPTION.with_borrow_mut(|x| *x = None);
     }
-}
 
-/// 身份管理函数
-pub mod identity {
-    use super::*;
+    /// 记录本地玩家所在竞技场的实体订阅
+    pub fn set_arena_subscription(arena_id: u32, handle: SubscriptionHandle) {
+        ARENA_SUBSCRIPTION.with_borrow_mut(|x| *x = Some((arena_id, handle)));
+    }
 
-    /// 设置本地身份
-    pub fn set_local_identity(identity: Identity) {
-        let _ = LOCAL_IDENTITY.set(identity);
+    /// 当前订阅实体的竞技场
+    pub fn arena_subscription_id() -> Option<u32> {
+        ARENA_SUBSCRIPTION.with_borrow(|x| x.as_ref().map(|(arena_id, _)| *arena_id))
     }
 
-    /// 获取本地身份
-    pub fn get_local_identity() -> Option<Identity> {
-        LOCAL_IDENTITY.get().copied()
+    /// 取出竞技场的实体订阅，以便取消
+    pub fn take_arena_subscription() -> Option<SubscriptionHandle> {
+        ARENA_SUBSCRIPTION.with_borrow_mut(|x| x.take().map(|(_, handle)| handle))
     }
-}
 
-/// 实体管理函数
-pub mod entities {
-    use super::*;
+    /// 设置连接状态
+    pub fn set_status(status: ConnectionStatus) {
+        CONNECTION_STATUS.with_borrow_mut(|x| *x = status);
+    }
 
-    /// 插入实体
-    pub fn insert_entity(entity_id: u32, controller: EntityController) {
-        ENTITIES.with_borrow_mut(|entities| {
-            entities.insert(entity_id, controller);
-        });
+    /// 获取连接状态
+    pub fn status() -> ConnectionStatus {
+        CONNECTION_STATUS.with_borrow(|x| x.clone())
     }
 
-    /// 获取实体（注意：EntityController 没有实现 Clone，所以不能直接返回拷贝）
-    pub fn with_entity<F, R>(entity_id: u32, f: F) -> Option<R>
-    where
-        F: FnOnce(&EntityController) -> R,
-    {
-        ENTITIES.with_borrow(|entities| {
-            entities.get(&entity_id).map(f)
-        })
+    /// 设置连接设置，之后的连接和重连都使用它
+    pub fn set_settings(settings: ConnectionSettings) {
+        CONNECTION_SETTINGS.with_borrow_mut(|x| *x = settings);
     }
 
-    /// 移除实体
-    pub fn remove_entity(entity_id: u32) -> Option<EntityController> {
-        ENTITIES.with_borrow_mut(|entities| {
-            entities.remove(&entity_id)
-        })
+    /// 获取连接设置
+    pub fn settings() -> ConnectionSettings {
+        CONNECTION_SETTINGS.with_borrow(|x| x.clone())
     }
 
-    /// 更新实体
-    pub fn update_entity<F>(entity_id: u32, updater: F) -> bool 
+    /// 访客令牌只保存在内存中，重连时沿用同一身份
+    pub fn set_guest_token(token: String) {
+        GUEST_TOKEN.with_borrow_mut(|x| *x = Some(token));
+    }
+
+    /// 获取访客令牌
+    pub fn guest_token() -> Option<String> {
+        GUEST_TOKEN.with_borrow(|x| x.clone())
+    }
+
+    /// 访问断线重连的退避状态
+    pub fn with_backoff<F, R>(f: F) -> R
     where
-        F: FnOnce(&mut EntityController),
+        F: FnOnce(&mut ReconnectBackoff) -> R,
     {
-        ENTITIES.with_borrow_mut(|entities| {
-            if let Some(entity) = entities.get_mut(&entity_id) {
-                updater(entity);
-                true
-            } else {
-                false
-            }
-        })
+        RECONNECT_BACKOFF.with_borrow_mut(f)
+    }
+}
+
+/// 身份管理函数
+pub mod identity {
+    use super::*;
+
+    /// 设置本地身份
+    pub fn set_local_identity(identity: Identity) {
+        *LOCAL_IDENTITY.write().unwrap() = Some(identity);
+    }
+
+    /// 获取本地身份
+    pub fn get_local_identity() -> Option<Identity> {
+        *LOCAL_IDENTITY.read().unwrap()
     }
 }
 
@@ -136,6 +159,11 @@ pub mod players {
         })
     }
 
+    /// 移除所有玩家
+    pub fn remove_all() -> Vec<Gd<PlayerController>> {
+        PLAYERS.with_borrow_mut(|players| players.drain().map(|(_, player)| player).collect())
+    }
+
     /// 检查玩家是否存在
     pub fn contains_player(player_id: u32) -> bool {
         PLAYERS.with_borrow(|players| {
@@ -210,4 +238,212 @@ pub mod food_batch_renderer {
             false
         }
     }
-}
\ No newline at end of file
+}
+
+/// 圆形批量渲染器状态管理函数
+pub mod circle_batch_renderer {
+    use super::*;
+
+    /// 设置圆形批量渲染器实例
+    pub fn set_instance(instance: Gd<CircleBatchRenderer>) {
+        CIRCLE_BATCH_RENDERER.with_borrow_mut(|renderer| {
+            *renderer = Some(instance);
+        });
+    }
+
+    /// 获取圆形批量渲染器实例
+    pub fn get_instance() -> Option<Gd<CircleBatchRenderer>> {
+        CIRCLE_BATCH_RENDERER.with_borrow(|renderer| {
+            renderer.clone()
+        })
+    }
+
+    /// 检查是否是圆形实体
+    pub fn is_circle_entity(entity_id: u32) -> bool {
+        if let Some(renderer) = get_instance() {
+            renderer.bind().contains_circle(entity_id)
+        } else {
+            false
+        }
+    }
+}
+
+/// 死亡界面状态管理函数
+pub mod death_screen {
+    use super::*;
+
+    /// 设置死亡界面实例
+    pub fn set_instance(instance: Gd<DeathScreen>) {
+        DEATH_SCREEN.with_borrow_mut(|screen| {
+            *screen = Some(instance);
+        });
+    }
+
+    /// 获取死亡界面实例
+    pub fn get_instance() -> Option<Gd<DeathScreen>> {
+        DEATH_SCREEN.with_borrow(|screen| {
+            screen.clone()
+        })
+    }
+}
+
+/// 主菜单状态管理函数
+pub mod main_menu {
+    use super::*;
+
+    /// 设置主菜单实例
+    pub fn set_instance(instance: Gd<MainMenu>) {
+        MAIN_MENU.with_borrow_mut(|menu| {
+            *menu = Some(instance);
+        });
+    }
+
+    /// 获取主菜单实例
+    pub fn get_instance() -> Option<Gd<MainMenu>> {
+        MAIN_MENU.with_borrow(|menu| {
+            menu.clone()
+        })
+    }
+}
+
+/// 游戏事件队列
+///
+/// SDK 回调中产生的事件先排队，由 GameManager 在回调结束后作为信号发出
+pub mod events {
+    use super::*;
+
+    /// 添加事件
+    pub fn push(event: GameEvent) {
+        PENDING_EVENTS.with_borrow_mut(|events| events.push(event));
+    }
+
+    /// 取出所有待发出的事件
+    pub fn take() -> Vec<GameEvent> {
+        PENDING_EVENTS.with_borrow_mut(std::mem::take)
+    }
+}
+
+/// 插值时钟管理函数
+///
+/// 估算服务器时钟与本地时钟的偏移，实体以“服务器时间 - 渲染延迟”渲染
+pub mod interpolation {
+    use super::*;
+    use godot::classes::Time;
+
+    /// 偏移误差超过该值时直接跳变，否则平滑逼近
+    const CLOCK_SNAP_THRESHOLD_SEC: f64 = 1.0;
+    const CLOCK_SMOOTHING: f64 = 0.05;
+
+    /// 本地单调时钟（秒）
+    pub fn local_time() -> f64 {
+        Time::singleton().get_ticks_usec() as f64 / 1_000_000.0
+    }
+
+    /// 设置渲染延迟
+    pub fn set_render_delay(delay_sec: f64) {
+        RENDER_DELAY_SEC.set(delay_sec.max(0.0));
+    }
+
+    /// 获取渲染延迟
+    pub fn render_delay() -> f64 {
+        RENDER_DELAY_SEC.get()
+    }
+
+    /// 收到服务器时间后更新时钟偏移
+    pub fn observe_server_time(server_time: f64) {
+        let sample = server_time - local_time();
+        let offset = match SERVER_CLOCK_OFFSET.get() {
+            Some(offset) if (sample - offset).abs() < CLOCK_SNAP_THRESHOLD_SEC => {
+                offset + (sample - offset) * CLOCK_SMOOTHING
+            }
+            _ => sample,
+        };
+        SERVER_CLOCK_OFFSET.set(Some(offset));
+    }
+
+    /// 估算的当前服务器时间（秒）
+    pub fn server_time() -> f64 {
+        local_time() + SERVER_CLOCK_OFFSET.get().unwrap_or(0.0)
+    }
+
+    /// 当前帧的渲染时间
+    pub fn render_time() -> f64 {
+        server_time() - render_delay()
+    }
+}
+
+/// 移动规则管理函数
+///
+/// 由 Config 表的回调更新，预测时不再每帧查表
+pub mod movement {
+    use super::*;
+
+    /// 设置当前的移动规则
+    pub fn set_rules(rules: MovementRules) {
+        MOVEMENT_RULES.with_borrow_mut(|x| *x = Some(rules));
+    }
+
+    /// 清除移动规则
+    pub fn clear_rules() {
+        MOVEMENT_RULES.with_borrow_mut(|x| *x = None);
+    }
+
+    /// 使用当前的移动规则，还没有收到 Config 时返回 None
+    pub fn with_rules<R>(f: impl FnOnce(&MovementRules) -> R) -> Option<R> {
+        MOVEMENT_RULES.with_borrow(|x| x.as_ref().map(f))
+    }
+}
+
+/// 本地输入历史管理函数
+pub mod input_history {
+    use super::*;
+
+    /// 记录发送的输入
+    pub fn record_sent(input: Vector2) {
+        let time = interpolation::local_time();
+        INPUT_HISTORY.with_borrow_mut(|history| history.on_sent(time, input));
+    }
+
+    /// 服务器确认了一次输入
+    pub fn acknowledge() {
+        let time = interpolation::local_time();
+        INPUT_HISTORY.with_borrow_mut(|history| history.on_acknowledged(time));
+    }
+
+    /// 读取输入历史
+    pub fn with<R>(f: impl FnOnce(&InputHistory) -> R) -> R {
+        INPUT_HISTORY.with_borrow(f)
+    }
+
+    /// 清除输入历史，重连后往返时间重新测量
+    pub fn clear() {
+        INPUT_HISTORY.with_borrow_mut(InputHistory::clear);
+    }
+}
+
+/// HUD 刷新标记
+///
+/// 由 SDK 回调设置，HUD 只在数据变化后的下一帧重建文本
+pub mod hud {
+    use super::*;
+
+    /// 聊天记录或玩家名字变化
+    pub fn mark_chat_dirty() {
+        CHAT_DIRTY.set(true);
+    }
+
+    /// 取出并清除聊天刷新标记
+    pub fn take_chat_dirty() -> bool {
+        CHAT_DIRTY.replace(false)
+    }
+
+    /// 排行榜或本地玩家所在竞技场变化
+    pub fn mark_leaderboard_dirty() {
+        LEADERBOARD_DIRTY.set(true);
+    }
+
+    /// 取出并清除排行榜刷新标记
+    pub fn take_leaderboard_dirty() -> bool {
+        LEADERBOARD_DIRTY.replace(false)
+    }
+}
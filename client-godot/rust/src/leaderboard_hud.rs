@@ -0,0 +1,86 @@
+use super::*;
+use crate::global_state::*;
+use godot::classes::{CanvasLayer, ICanvasLayer, RichTextLabel};
+
+/// 排行榜 HUD
+///
+/// 显示服务器维护的 `leaderboard` 表，并高亮本地玩家
+#[derive(GodotClass)]
+#[class(init, base=CanvasLayer)]
+pub struct LeaderboardHud {
+    base: Base<CanvasLayer>,
+
+    label: Option<Gd<RichTextLabel>>,
+}
+
+impl LeaderboardHud {
+    const HIGHLIGHT_COLOR: &'static str = "yellow";
+
+    /// 根据排行榜数据生成 BBCode 文本
+    fn build_text(&self) -> String {
+        let Some(conn) = connection::get_connection() else {
+            return String::new();
+        };
+
+        let local_player_id = players::get_local_player().map(|x| x.bind().player_id());
+        let Some(arena_id) = local_arena_id() else {
+            return String::new();
+        };
+
+        let mut entries: Vec<LeaderboardEntry> = conn
+            .db
+            .leaderboard()
+            .iter()
+            .filter(|x| x.arena_id == arena_id)
+            .collect();
+        entries.sort_by_key(|x| x.rank);
+
+        let mut text = String::from("[b]Leaderboard[/b]\n");
+        for entry in entries {
+            let line = format!("{}. {} - {}", entry.rank, entry.name, entry.total_mass);
+            if Some(entry.player_id) == local_player_id {
+                text.push_str(&format!("[color={}]{}[/color]\n", Self::HIGHLIGHT_COLOR, line));
+            } else {
+                text.push_str(&line);
+                text.push('\n');
+            }
+        }
+        text
+    }
+}
+
+#[godot_api]
+impl ICanvasLayer for LeaderboardHud {
+    fn ready(&mut self) {
+        let mut label = RichTextLabel::new_alloc();
+        label.set_name("LeaderboardLabel");
+        label.set_use_bbcode(true);
+        label.set_fit_content(true);
+        label.set_scroll_active(false);
+        label.set_mouse_filter(godot::classes::control::MouseFilter::IGNORE);
+
+        // 固定在屏幕右上角
+        label.set_anchor(godot::builtin::Side::LEFT, 1.0);
+        label.set_anchor(godot::builtin::Side::RIGHT, 1.0);
+        label.set_offset(godot::builtin::Side::LEFT, -320.0);
+        label.set_offset(godot::builtin::Side::TOP, 10.0);
+        label.set_offset(godot::builtin::Side::RIGHT, -10.0);
+        label.add_theme_font_size_override("normal_font_size", 24);
+        label.add_theme_font_size_override("bold_font_size", 28);
+
+        self.base_mut().add_child(&label);
+        self.label = Some(label);
+    }
+
+    /// 只在排行榜或本地玩家变化后重建文本
+    fn process(&mut self, _delta: f64) {
+        if !hud::take_leaderboard_dirty() {
+            return;
+        }
+
+        let text = self.build_text();
+        if let Some(label) = &mut self.label {
+            label.set_text(&text);
+        }
+    }
+}
@@ -1,23 +1,43 @@
 mod camera_controller;
-mod circle_controller;
+mod chat_panel;
+mod circle_batch_renderer;
+mod connection_manager;
+mod connection_settings;
+mod death_screen;
 mod entity_controller;
 mod extensions;
 mod food_batch_renderer;
 mod game_manager;
 mod global_state;
+mod leaderboard_hud;
+mod main_menu;
 mod module_bindings;
+mod multimesh_renderer;
 mod player_controller;
+mod prediction;
 mod prefab_manager;
+mod snapshot_buffer;
+mod spacetime_bridge;
 
 pub use camera_controller::*;
-pub use circle_controller::*;
+pub use chat_panel::*;
+pub use circle_batch_renderer::*;
+pub use connection_manager::*;
+pub use connection_settings::*;
+pub use death_screen::*;
 pub use entity_controller::*;
 pub use food_batch_renderer::*;
 pub use game_manager::*;
 pub use global_state::*;
+pub use leaderboard_hud::*;
+pub use main_menu::*;
 pub use module_bindings::*;
+pub use multimesh_renderer::*;
 pub use player_controller::*;
+pub use prediction::*;
 pub use prefab_manager::*;
+pub use snapshot_buffer::*;
+pub use spacetime_bridge::*;
 
 pub use godot::classes::Engine;
 pub use godot::prelude::*;
@@ -0,0 +1,235 @@
+use super::*;
+use crate::global_state::*;
+use godot::classes::{
+    Button, CanvasLayer, ColorRect, ConfigFile, ICanvasLayer, Label, LineEdit, VBoxContainer,
+};
+
+/// 与服务器的 `MAX_NAME_LENGTH` 一致
+pub const MAX_NAME_LENGTH: usize = 16;
+
+/// 本地设置文件，保存上次使用的名字
+const SETTINGS_PATH: &str = "user://settings.cfg";
+
+/// 在发送之前检查名字，规则与服务器的 `validate_name` 一致（名字是否重复只有服务器知道）
+pub fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Name must not be empty".into());
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("Name must be at most {} characters", MAX_NAME_LENGTH));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == ' ' || c == '_' || c == '-')
+    {
+        return Err("Name may only contain letters, digits, spaces, '_' and '-'".into());
+    }
+    Ok(name.to_string())
+}
+
+/// 主菜单
+///
+/// 显示连接状态，输入名字后点击 Play 才进入游戏；服务器拒绝时显示原因
+#[derive(GodotClass)]
+#[class(init, base=CanvasLayer)]
+pub struct MainMenu {
+    base: Base<CanvasLayer>,
+
+    status_label: Option<Gd<Label>>,
+    name_edit: Option<Gd<LineEdit>>,
+    play_button: Option<Gd<Button>>,
+    error_label: Option<Gd<Label>>,
+
+    /// 已发送 enter_game，等待服务器结果
+    pending: bool,
+}
+
+impl MainMenu {
+    /// 每个凭据配置使用各自的名字
+    fn settings_section() -> String {
+        let profile = connection::settings().profile;
+        if profile.is_empty() {
+            "player".to_string()
+        } else {
+            format!("player-{}", profile)
+        }
+    }
+
+    fn load_name() -> String {
+        let mut config = ConfigFile::new_gd();
+        if config.load(SETTINGS_PATH) != godot::global::Error::OK {
+            return String::new();
+        }
+        config
+            .get_value_ex(Self::settings_section().as_str(), "name")
+            .default(&"".to_variant())
+            .done()
+            .try_to::<GString>()
+            .map(|x| x.to_string())
+            .unwrap_or_default()
+    }
+
+    fn save_name(name: &str) {
+        let mut config = ConfigFile::new_gd();
+        // 文件不存在时从空配置开始
+        let _ = config.load(SETTINGS_PATH);
+        config.set_value(Self::settings_section().as_str(), "name", &name.to_variant());
+        let error = config.save(SETTINGS_PATH);
+        if error != godot::global::Error::OK {
+            godot_error!("Failed to save {}: {:?}", SETTINGS_PATH, error);
+        }
+    }
+
+    fn set_error(&mut self, message: &str) {
+        if let Some(label) = &mut self.error_label {
+            label.set_text(message);
+        }
+    }
+
+    /// 显示主菜单，例如重连之后需要重新进入游戏
+    pub fn show_menu(&mut self) {
+        self.pending = false;
+        self.set_error("");
+        self.base_mut().set_visible(true);
+    }
+
+    /// 处理 enter_game 的结果，成功则隐藏菜单
+    pub fn on_enter_game_result(&mut self, result: Result<(), String>) {
+        self.pending = false;
+        match result {
+            Ok(()) => {
+                self.set_error("");
+                self.base_mut().set_visible(false);
+            }
+            Err(message) => self.set_error(&message),
+        }
+    }
+}
+
+#[godot_api]
+impl ICanvasLayer for MainMenu {
+    fn ready(&mut self) {
+        // 半透明背景
+        let mut background = ColorRect::new_alloc();
+        background.set_color(Color::from_rgba(0.0, 0.0, 0.0, 0.6));
+        background.set_anchor(godot::builtin::Side::RIGHT, 1.0);
+        background.set_anchor(godot::builtin::Side::BOTTOM, 1.0);
+        self.base_mut().add_child(&background);
+
+        let mut container = VBoxContainer::new_alloc();
+        container.set_anchor(godot::builtin::Side::LEFT, 0.5);
+        container.set_anchor(godot::builtin::Side::TOP, 0.5);
+        container.set_anchor(godot::builtin::Side::RIGHT, 0.5);
+        container.set_anchor(godot::builtin::Side::BOTTOM, 0.5);
+        container.set_offset(godot::builtin::Side::LEFT, -250.0);
+        container.set_offset(godot::builtin::Side::TOP, -150.0);
+        container.set_offset(godot::builtin::Side::RIGHT, 250.0);
+        container.set_offset(godot::builtin::Side::BOTTOM, 150.0);
+
+        let mut title = Label::new_alloc();
+        title.set_text("Blackholio");
+        title.set_horizontal_alignment(godot::global::HorizontalAlignment::CENTER);
+        title.add_theme_font_size_override("font_size", 48);
+        container.add_child(&title);
+
+        let mut status_label = Label::new_alloc();
+        status_label.set_horizontal_alignment(godot::global::HorizontalAlignment::CENTER);
+        container.add_child(&status_label);
+
+        let mut name_edit = LineEdit::new_alloc();
+        name_edit.set_placeholder("Enter your name");
+        name_edit.set_max_length(MAX_NAME_LENGTH as i32);
+        name_edit.set_text(&Self::load_name());
+        name_edit.add_theme_font_size_override("font_size", 32);
+        name_edit.connect("text_submitted", &self.base().callable("on_name_submitted"));
+        container.add_child(&name_edit);
+
+        let mut play_button = Button::new_alloc();
+        play_button.set_text("Play");
+        play_button.set_disabled(true);
+        play_button.add_theme_font_size_override("font_size", 32);
+        play_button.connect("pressed", &self.base().callable("on_play_pressed"));
+        container.add_child(&play_button);
+
+        let mut error_label = Label::new_alloc();
+        error_label.set_horizontal_alignment(godot::global::HorizontalAlignment::CENTER);
+        error_label.add_theme_color_override("font_color", Color::from_rgb(1.0, 0.4, 0.4));
+        container.add_child(&error_label);
+
+        self.base_mut().add_child(&container);
+        name_edit.grab_focus();
+
+        self.status_label = Some(status_label);
+        self.name_edit = Some(name_edit);
+        self.play_button = Some(play_button);
+        self.error_label = Some(error_label);
+    }
+
+    fn process(&mut self, _delta: f64) {
+        if !self.base().is_visible() {
+            return;
+        }
+
+        let status = connection::status();
+        let ready = status == ConnectionStatus::Ready;
+        if let Some(label) = &mut self.status_label {
+            label.set_text(&status.description());
+        }
+        let disabled = !ready || self.pending;
+        if let Some(button) = &mut self.play_button {
+            button.set_disabled(disabled);
+        }
+    }
+}
+
+#[godot_api]
+impl MainMenu {
+    #[func]
+    fn on_name_submitted(&mut self, _text: GString) {
+        self.on_play_pressed();
+    }
+
+    #[func]
+    fn on_play_pressed(&mut self) {
+        if self.pending || connection::status() != ConnectionStatus::Ready {
+            return;
+        }
+        let Some(name_edit) = &self.name_edit else {
+            return;
+        };
+
+        let name = match validate_name(&name_edit.get_text().to_string()) {
+            Ok(name) => name,
+            Err(message) => {
+                self.set_error(&message);
+                return;
+            }
+        };
+        Self::save_name(&name);
+
+        let Some(conn) = connection::get_connection() else {
+            return;
+        };
+        match conn.reducers.enter_game(name) {
+            Ok(()) => {
+                self.pending = true;
+                self.set_error("");
+            }
+            Err(e) => self.set_error(&format!("Failed to enter game: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_trimmed_and_checked_like_the_server() {
+        assert_eq!(validate_name("  Alice "), Ok("Alice".to_string()));
+        assert!(validate_name("   ").is_err());
+        assert!(validate_name("a".repeat(MAX_NAME_LENGTH + 1).as_str()).is_err());
+        assert!(validate_name("<script>").is_err());
+    }
+}
@@ -16,6 +16,7 @@ pub struct Circle {
     pub direction: DbVector2,
     pub speed: f32,
     pub last_split_time: __sdk::Timestamp,
+    pub impulse: DbVector2,
 }
 
 impl __sdk::InModule for Circle {
@@ -10,7 +10,18 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[sats(crate = __lib)]
 pub struct Config {
     pub id: u32,
-    pub world_size: u64,
+    pub food_mass_min: u32,
+    pub food_mass_max: u32,
+    pub start_player_mass: u32,
+    pub start_player_speed: u32,
+    pub minimum_safe_mass_ratio: f32,
+    pub min_mass_to_split: u32,
+    pub max_circles_per_player: u32,
+    pub split_cooldown_ms: u64,
+    pub split_recombine_delay_ms: u64,
+    pub spawn_food_interval_ms: u64,
+    pub move_all_players_interval_ms: u64,
+    pub min_arena_population: u32,
 }
 
 impl __sdk::InModule for Config {
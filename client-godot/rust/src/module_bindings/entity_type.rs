@@ -12,6 +12,7 @@ use super::db_vector_2_type::DbVector2;
 #[sats(crate = __lib)]
 pub struct Entity {
     pub entity_id: u32,
+    pub arena_id: u32,
     pub position: DbVector2,
     pub mass: u32,
 }
@@ -6,11 +6,32 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod add_admin_reducer;
+pub mod add_bot_reducer;
+pub mod admin_table;
+pub mod admin_type;
+pub mod arena_table;
+pub mod arena_type;
+pub mod ban_identity_reducer;
+pub mod banned_identity_table;
+pub mod banned_identity_type;
+pub mod bot_table;
+pub mod bot_timer_table;
+pub mod bot_timer_type;
+pub mod bot_type;
+pub mod chat_cleanup_timer_table;
+pub mod chat_cleanup_timer_type;
+pub mod chat_message_table;
+pub mod chat_message_type;
 pub mod circle_table;
 pub mod circle_type;
+pub mod clean_chat_messages_reducer;
+pub mod clean_kill_events_reducer;
+pub mod clear_arena_reducer;
 pub mod config_table;
 pub mod config_type;
 pub mod connect_reducer;
+pub mod create_arena_reducer;
 pub mod db_vector_2_type;
 pub mod debug_reducer;
 pub mod disconnect_reducer;
@@ -19,22 +40,77 @@ pub mod entity_table;
 pub mod entity_type;
 pub mod food_table;
 pub mod food_type;
+pub mod join_arena_reducer;
+pub mod kick_player_reducer;
+pub mod kill_event_cleanup_timer_table;
+pub mod kill_event_cleanup_timer_type;
+pub mod kill_event_table;
+pub mod kill_event_type;
+pub mod leaderboard_entry_type;
+pub mod leaderboard_table;
+pub mod leaderboard_timer_table;
+pub mod leaderboard_timer_type;
+pub mod list_arenas_reducer;
 pub mod logged_out_player_table;
 pub mod move_all_players_reducer;
 pub mod move_all_players_timer_table;
 pub mod move_all_players_timer_type;
+pub mod player_split_reducer;
+pub mod player_stats_table;
+pub mod player_stats_type;
 pub mod player_table;
 pub mod player_type;
+pub mod rate_limit_bucket_table;
+pub mod rate_limit_bucket_type;
+pub mod rate_limit_notice_table;
+pub mod rate_limit_notice_type;
+pub mod rate_limited_action_type;
+pub mod remove_admin_reducer;
+pub mod remove_bot_reducer;
+pub mod rename_player_reducer;
+pub mod respawn_reducer;
+pub mod send_chat_reducer;
 pub mod spawn_food_reducer;
 pub mod spawn_food_timer_table;
 pub mod spawn_food_timer_type;
+pub mod unban_identity_reducer;
+pub mod update_bots_reducer;
+pub mod update_config_reducer;
+pub mod update_leaderboard_reducer;
 pub mod update_player_input_reducer;
-
+pub mod world_tick_table;
+pub mod world_tick_type;
+
+pub use add_admin_reducer::{add_admin, set_flags_for_add_admin, AddAdminCallbackId};
+pub use add_bot_reducer::{add_bot, set_flags_for_add_bot, AddBotCallbackId};
+pub use admin_table::*;
+pub use admin_type::Admin;
+pub use arena_table::*;
+pub use arena_type::Arena;
+pub use ban_identity_reducer::{ban_identity, set_flags_for_ban_identity, BanIdentityCallbackId};
+pub use banned_identity_table::*;
+pub use banned_identity_type::BannedIdentity;
+pub use bot_table::*;
+pub use bot_timer_table::*;
+pub use bot_timer_type::BotTimer;
+pub use bot_type::Bot;
+pub use chat_cleanup_timer_table::*;
+pub use chat_cleanup_timer_type::ChatCleanupTimer;
+pub use chat_message_table::*;
+pub use chat_message_type::ChatMessage;
 pub use circle_table::*;
 pub use circle_type::Circle;
+pub use clean_chat_messages_reducer::{
+    clean_chat_messages, set_flags_for_clean_chat_messages, CleanChatMessagesCallbackId,
+};
+pub use clean_kill_events_reducer::{
+    clean_kill_events, set_flags_for_clean_kill_events, CleanKillEventsCallbackId,
+};
+pub use clear_arena_reducer::{clear_arena, set_flags_for_clear_arena, ClearArenaCallbackId};
 pub use config_table::*;
 pub use config_type::Config;
 pub use connect_reducer::{connect, set_flags_for_connect, ConnectCallbackId};
+pub use create_arena_reducer::{create_arena, set_flags_for_create_arena, CreateArenaCallbackId};
 pub use db_vector_2_type::DbVector2;
 pub use debug_reducer::{debug, set_flags_for_debug, DebugCallbackId};
 pub use disconnect_reducer::{disconnect, set_flags_for_disconnect, DisconnectCallbackId};
@@ -43,20 +119,58 @@ pub use entity_table::*;
 pub use entity_type::Entity;
 pub use food_table::*;
 pub use food_type::Food;
+pub use join_arena_reducer::{join_arena, set_flags_for_join_arena, JoinArenaCallbackId};
+pub use kick_player_reducer::{kick_player, set_flags_for_kick_player, KickPlayerCallbackId};
+pub use kill_event_cleanup_timer_table::*;
+pub use kill_event_cleanup_timer_type::KillEventCleanupTimer;
+pub use kill_event_table::*;
+pub use kill_event_type::KillEvent;
+pub use leaderboard_entry_type::LeaderboardEntry;
+pub use leaderboard_table::*;
+pub use leaderboard_timer_table::*;
+pub use leaderboard_timer_type::LeaderboardTimer;
+pub use list_arenas_reducer::{list_arenas, set_flags_for_list_arenas, ListArenasCallbackId};
 pub use logged_out_player_table::*;
 pub use move_all_players_reducer::{
     move_all_players, set_flags_for_move_all_players, MoveAllPlayersCallbackId,
 };
 pub use move_all_players_timer_table::*;
 pub use move_all_players_timer_type::MoveAllPlayersTimer;
+pub use player_split_reducer::{player_split, set_flags_for_player_split, PlayerSplitCallbackId};
+pub use player_stats_table::*;
+pub use player_stats_type::PlayerStats;
 pub use player_table::*;
 pub use player_type::Player;
+pub use rate_limit_bucket_table::*;
+pub use rate_limit_bucket_type::RateLimitBucket;
+pub use rate_limit_notice_table::*;
+pub use rate_limit_notice_type::RateLimitNotice;
+pub use rate_limited_action_type::RateLimitedAction;
+pub use remove_admin_reducer::{remove_admin, set_flags_for_remove_admin, RemoveAdminCallbackId};
+pub use remove_bot_reducer::{remove_bot, set_flags_for_remove_bot, RemoveBotCallbackId};
+pub use rename_player_reducer::{
+    rename_player, set_flags_for_rename_player, RenamePlayerCallbackId,
+};
+pub use respawn_reducer::{respawn, set_flags_for_respawn, RespawnCallbackId};
+pub use send_chat_reducer::{send_chat, set_flags_for_send_chat, SendChatCallbackId};
 pub use spawn_food_reducer::{set_flags_for_spawn_food, spawn_food, SpawnFoodCallbackId};
 pub use spawn_food_timer_table::*;
 pub use spawn_food_timer_type::SpawnFoodTimer;
+pub use unban_identity_reducer::{
+    set_flags_for_unban_identity, unban_identity, UnbanIdentityCallbackId,
+};
+pub use update_bots_reducer::{set_flags_for_update_bots, update_bots, UpdateBotsCallbackId};
+pub use update_config_reducer::{
+    set_flags_for_update_config, update_config, UpdateConfigCallbackId,
+};
+pub use update_leaderboard_reducer::{
+    set_flags_for_update_leaderboard, update_leaderboard, UpdateLeaderboardCallbackId,
+};
 pub use update_player_input_reducer::{
     set_flags_for_update_player_input, update_player_input, UpdatePlayerInputCallbackId,
 };
+pub use world_tick_table::*;
+pub use world_tick_type::WorldTick;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -66,13 +180,80 @@ pub use update_player_input_reducer::{
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    AddAdmin {
+        identity: __sdk::Identity,
+    },
+    AddBot {
+        arena_id: u32,
+    },
+    BanIdentity {
+        identity: __sdk::Identity,
+        reason: String,
+    },
+    CleanChatMessages {
+        timer: ChatCleanupTimer,
+    },
+    CleanKillEvents {
+        timer: KillEventCleanupTimer,
+    },
+    ClearArena {
+        arena_id: u32,
+    },
     Connect,
+    CreateArena {
+        name: String,
+        world_size: u64,
+        target_food_count: u32,
+        max_players: u32,
+    },
     Debug,
     Disconnect,
-    EnterGame { name: String },
-    MoveAllPlayers { timer: MoveAllPlayersTimer },
-    SpawnFood { timer: SpawnFoodTimer },
-    UpdatePlayerInput { direction: DbVector2 },
+    EnterGame {
+        name: String,
+    },
+    JoinArena {
+        arena_id: u32,
+    },
+    KickPlayer {
+        player_id: u32,
+    },
+    ListArenas,
+    MoveAllPlayers {
+        timer: MoveAllPlayersTimer,
+    },
+    PlayerSplit,
+    RemoveAdmin {
+        identity: __sdk::Identity,
+    },
+    RemoveBot {
+        bot_id: u32,
+    },
+    RenamePlayer {
+        player_id: u32,
+        name: String,
+    },
+    Respawn,
+    SendChat {
+        text: String,
+    },
+    SpawnFood {
+        timer: SpawnFoodTimer,
+    },
+    UnbanIdentity {
+        identity: __sdk::Identity,
+    },
+    UpdateBots {
+        timer: BotTimer,
+    },
+    UpdateConfig {
+        config: Config,
+    },
+    UpdateLeaderboard {
+        timer: LeaderboardTimer,
+    },
+    UpdatePlayerInput {
+        direction: DbVector2,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -82,12 +263,32 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AddAdmin { .. } => "add_admin",
+            Reducer::AddBot { .. } => "add_bot",
+            Reducer::BanIdentity { .. } => "ban_identity",
+            Reducer::CleanChatMessages { .. } => "clean_chat_messages",
+            Reducer::CleanKillEvents { .. } => "clean_kill_events",
+            Reducer::ClearArena { .. } => "clear_arena",
             Reducer::Connect => "connect",
+            Reducer::CreateArena { .. } => "create_arena",
             Reducer::Debug => "debug",
             Reducer::Disconnect => "disconnect",
             Reducer::EnterGame { .. } => "enter_game",
+            Reducer::JoinArena { .. } => "join_arena",
+            Reducer::KickPlayer { .. } => "kick_player",
+            Reducer::ListArenas => "list_arenas",
             Reducer::MoveAllPlayers { .. } => "move_all_players",
+            Reducer::PlayerSplit => "player_split",
+            Reducer::RemoveAdmin { .. } => "remove_admin",
+            Reducer::RemoveBot { .. } => "remove_bot",
+            Reducer::RenamePlayer { .. } => "rename_player",
+            Reducer::Respawn => "respawn",
+            Reducer::SendChat { .. } => "send_chat",
             Reducer::SpawnFood { .. } => "spawn_food",
+            Reducer::UnbanIdentity { .. } => "unban_identity",
+            Reducer::UpdateBots { .. } => "update_bots",
+            Reducer::UpdateConfig { .. } => "update_config",
+            Reducer::UpdateLeaderboard { .. } => "update_leaderboard",
             Reducer::UpdatePlayerInput { .. } => "update_player_input",
         }
     }
@@ -96,11 +297,52 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "add_admin" => Ok(
+                __sdk::parse_reducer_args::<add_admin_reducer::AddAdminArgs>(
+                    "add_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "add_bot" => Ok(__sdk::parse_reducer_args::<add_bot_reducer::AddBotArgs>(
+                "add_bot",
+                &value.args,
+            )?
+            .into()),
+            "ban_identity" => Ok(
+                __sdk::parse_reducer_args::<ban_identity_reducer::BanIdentityArgs>(
+                    "ban_identity",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "clean_chat_messages" => Ok(__sdk::parse_reducer_args::<
+                clean_chat_messages_reducer::CleanChatMessagesArgs,
+            >("clean_chat_messages", &value.args)?
+            .into()),
+            "clean_kill_events" => Ok(__sdk::parse_reducer_args::<
+                clean_kill_events_reducer::CleanKillEventsArgs,
+            >("clean_kill_events", &value.args)?
+            .into()),
+            "clear_arena" => Ok(
+                __sdk::parse_reducer_args::<clear_arena_reducer::ClearArenaArgs>(
+                    "clear_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "connect" => Ok(__sdk::parse_reducer_args::<connect_reducer::ConnectArgs>(
                 "connect",
                 &value.args,
             )?
             .into()),
+            "create_arena" => Ok(
+                __sdk::parse_reducer_args::<create_arena_reducer::CreateArenaArgs>(
+                    "create_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "debug" => Ok(__sdk::parse_reducer_args::<debug_reducer::DebugArgs>(
                 "debug",
                 &value.args,
@@ -120,10 +362,68 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "join_arena" => Ok(
+                __sdk::parse_reducer_args::<join_arena_reducer::JoinArenaArgs>(
+                    "join_arena",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "kick_player" => Ok(
+                __sdk::parse_reducer_args::<kick_player_reducer::KickPlayerArgs>(
+                    "kick_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "list_arenas" => Ok(
+                __sdk::parse_reducer_args::<list_arenas_reducer::ListArenasArgs>(
+                    "list_arenas",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "move_all_players" => Ok(__sdk::parse_reducer_args::<
                 move_all_players_reducer::MoveAllPlayersArgs,
             >("move_all_players", &value.args)?
             .into()),
+            "player_split" => Ok(
+                __sdk::parse_reducer_args::<player_split_reducer::PlayerSplitArgs>(
+                    "player_split",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "remove_admin" => Ok(
+                __sdk::parse_reducer_args::<remove_admin_reducer::RemoveAdminArgs>(
+                    "remove_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "remove_bot" => Ok(
+                __sdk::parse_reducer_args::<remove_bot_reducer::RemoveBotArgs>(
+                    "remove_bot",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "rename_player" => Ok(__sdk::parse_reducer_args::<
+                rename_player_reducer::RenamePlayerArgs,
+            >("rename_player", &value.args)?
+            .into()),
+            "respawn" => Ok(__sdk::parse_reducer_args::<respawn_reducer::RespawnArgs>(
+                "respawn",
+                &value.args,
+            )?
+            .into()),
+            "send_chat" => Ok(
+                __sdk::parse_reducer_args::<send_chat_reducer::SendChatArgs>(
+                    "send_chat",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "spawn_food" => Ok(
                 __sdk::parse_reducer_args::<spawn_food_reducer::SpawnFoodArgs>(
                     "spawn_food",
@@ -131,6 +431,25 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 )?
                 .into(),
             ),
+            "unban_identity" => Ok(__sdk::parse_reducer_args::<
+                unban_identity_reducer::UnbanIdentityArgs,
+            >("unban_identity", &value.args)?
+            .into()),
+            "update_bots" => Ok(
+                __sdk::parse_reducer_args::<update_bots_reducer::UpdateBotsArgs>(
+                    "update_bots",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "update_config" => Ok(__sdk::parse_reducer_args::<
+                update_config_reducer::UpdateConfigArgs,
+            >("update_config", &value.args)?
+            .into()),
+            "update_leaderboard" => Ok(__sdk::parse_reducer_args::<
+                update_leaderboard_reducer::UpdateLeaderboardArgs,
+            >("update_leaderboard", &value.args)?
+            .into()),
             "update_player_input" => Ok(__sdk::parse_reducer_args::<
                 update_player_input_reducer::UpdatePlayerInputArgs,
             >("update_player_input", &value.args)?
@@ -149,14 +468,29 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
+    arena: __sdk::TableUpdate<Arena>,
+    banned_identity: __sdk::TableUpdate<BannedIdentity>,
+    bot: __sdk::TableUpdate<Bot>,
+    bot_timer: __sdk::TableUpdate<BotTimer>,
+    chat_cleanup_timer: __sdk::TableUpdate<ChatCleanupTimer>,
+    chat_message: __sdk::TableUpdate<ChatMessage>,
     circle: __sdk::TableUpdate<Circle>,
     config: __sdk::TableUpdate<Config>,
     entity: __sdk::TableUpdate<Entity>,
     food: __sdk::TableUpdate<Food>,
+    kill_event: __sdk::TableUpdate<KillEvent>,
+    kill_event_cleanup_timer: __sdk::TableUpdate<KillEventCleanupTimer>,
+    leaderboard: __sdk::TableUpdate<LeaderboardEntry>,
+    leaderboard_timer: __sdk::TableUpdate<LeaderboardTimer>,
     logged_out_player: __sdk::TableUpdate<Player>,
     move_all_players_timer: __sdk::TableUpdate<MoveAllPlayersTimer>,
     player: __sdk::TableUpdate<Player>,
+    player_stats: __sdk::TableUpdate<PlayerStats>,
+    rate_limit_bucket: __sdk::TableUpdate<RateLimitBucket>,
+    rate_limit_notice: __sdk::TableUpdate<RateLimitNotice>,
     spawn_food_timer: __sdk::TableUpdate<SpawnFoodTimer>,
+    world_tick: __sdk::TableUpdate<WorldTick>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -165,6 +499,27 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update
+                    .admin
+                    .append(admin_table::parse_table_update(table_update)?),
+                "arena" => db_update
+                    .arena
+                    .append(arena_table::parse_table_update(table_update)?),
+                "banned_identity" => db_update
+                    .banned_identity
+                    .append(banned_identity_table::parse_table_update(table_update)?),
+                "bot" => db_update
+                    .bot
+                    .append(bot_table::parse_table_update(table_update)?),
+                "bot_timer" => db_update
+                    .bot_timer
+                    .append(bot_timer_table::parse_table_update(table_update)?),
+                "chat_cleanup_timer" => db_update
+                    .chat_cleanup_timer
+                    .append(chat_cleanup_timer_table::parse_table_update(table_update)?),
+                "chat_message" => db_update
+                    .chat_message
+                    .append(chat_message_table::parse_table_update(table_update)?),
                 "circle" => db_update
                     .circle
                     .append(circle_table::parse_table_update(table_update)?),
@@ -177,6 +532,18 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                 "food" => db_update
                     .food
                     .append(food_table::parse_table_update(table_update)?),
+                "kill_event" => db_update
+                    .kill_event
+                    .append(kill_event_table::parse_table_update(table_update)?),
+                "kill_event_cleanup_timer" => db_update.kill_event_cleanup_timer.append(
+                    kill_event_cleanup_timer_table::parse_table_update(table_update)?,
+                ),
+                "leaderboard" => db_update
+                    .leaderboard
+                    .append(leaderboard_table::parse_table_update(table_update)?),
+                "leaderboard_timer" => db_update
+                    .leaderboard_timer
+                    .append(leaderboard_timer_table::parse_table_update(table_update)?),
                 "logged_out_player" => db_update
                     .logged_out_player
                     .append(logged_out_player_table::parse_table_update(table_update)?),
@@ -186,9 +553,21 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
+                "player_stats" => db_update
+                    .player_stats
+                    .append(player_stats_table::parse_table_update(table_update)?),
+                "rate_limit_bucket" => db_update
+                    .rate_limit_bucket
+                    .append(rate_limit_bucket_table::parse_table_update(table_update)?),
+                "rate_limit_notice" => db_update
+                    .rate_limit_notice
+                    .append(rate_limit_notice_table::parse_table_update(table_update)?),
                 "spawn_food_timer" => db_update
                     .spawn_food_timer
                     .append(spawn_food_timer_table::parse_table_update(table_update)?),
+                "world_tick" => db_update
+                    .world_tick
+                    .append(world_tick_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -215,6 +594,27 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.arena = cache
+            .apply_diff_to_table::<Arena>("arena", &self.arena)
+            .with_updates_by_pk(|row| &row.arena_id);
+        diff.banned_identity = cache
+            .apply_diff_to_table::<BannedIdentity>("banned_identity", &self.banned_identity)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.bot = cache
+            .apply_diff_to_table::<Bot>("bot", &self.bot)
+            .with_updates_by_pk(|row| &row.bot_id);
+        diff.bot_timer = cache
+            .apply_diff_to_table::<BotTimer>("bot_timer", &self.bot_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.chat_cleanup_timer = cache
+            .apply_diff_to_table::<ChatCleanupTimer>("chat_cleanup_timer", &self.chat_cleanup_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.chat_message = cache
+            .apply_diff_to_table::<ChatMessage>("chat_message", &self.chat_message)
+            .with_updates_by_pk(|row| &row.message_id);
         diff.circle = cache
             .apply_diff_to_table::<Circle>("circle", &self.circle)
             .with_updates_by_pk(|row| &row.entity_id);
@@ -227,6 +627,21 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.food = cache
             .apply_diff_to_table::<Food>("food", &self.food)
             .with_updates_by_pk(|row| &row.entity_id);
+        diff.kill_event = cache
+            .apply_diff_to_table::<KillEvent>("kill_event", &self.kill_event)
+            .with_updates_by_pk(|row| &row.event_id);
+        diff.kill_event_cleanup_timer = cache
+            .apply_diff_to_table::<KillEventCleanupTimer>(
+                "kill_event_cleanup_timer",
+                &self.kill_event_cleanup_timer,
+            )
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.leaderboard = cache
+            .apply_diff_to_table::<LeaderboardEntry>("leaderboard", &self.leaderboard)
+            .with_updates_by_pk(|row| &row.entry_id);
+        diff.leaderboard_timer = cache
+            .apply_diff_to_table::<LeaderboardTimer>("leaderboard_timer", &self.leaderboard_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
         diff.logged_out_player = cache
             .apply_diff_to_table::<Player>("logged_out_player", &self.logged_out_player)
             .with_updates_by_pk(|row| &row.identity);
@@ -239,9 +654,21 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
+        diff.player_stats = cache
+            .apply_diff_to_table::<PlayerStats>("player_stats", &self.player_stats)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.rate_limit_bucket = cache
+            .apply_diff_to_table::<RateLimitBucket>("rate_limit_bucket", &self.rate_limit_bucket)
+            .with_updates_by_pk(|row| &row.bucket_id);
+        diff.rate_limit_notice = cache
+            .apply_diff_to_table::<RateLimitNotice>("rate_limit_notice", &self.rate_limit_notice)
+            .with_updates_by_pk(|row| &row.identity);
         diff.spawn_food_timer = cache
             .apply_diff_to_table::<SpawnFoodTimer>("spawn_food_timer", &self.spawn_food_timer)
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.world_tick = cache
+            .apply_diff_to_table::<WorldTick>("world_tick", &self.world_tick)
+            .with_updates_by_pk(|row| &row.arena_id);
 
         diff
     }
@@ -251,14 +678,29 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    arena: __sdk::TableAppliedDiff<'r, Arena>,
+    banned_identity: __sdk::TableAppliedDiff<'r, BannedIdentity>,
+    bot: __sdk::TableAppliedDiff<'r, Bot>,
+    bot_timer: __sdk::TableAppliedDiff<'r, BotTimer>,
+    chat_cleanup_timer: __sdk::TableAppliedDiff<'r, ChatCleanupTimer>,
+    chat_message: __sdk::TableAppliedDiff<'r, ChatMessage>,
     circle: __sdk::TableAppliedDiff<'r, Circle>,
     config: __sdk::TableAppliedDiff<'r, Config>,
     entity: __sdk::TableAppliedDiff<'r, Entity>,
     food: __sdk::TableAppliedDiff<'r, Food>,
+    kill_event: __sdk::TableAppliedDiff<'r, KillEvent>,
+    kill_event_cleanup_timer: __sdk::TableAppliedDiff<'r, KillEventCleanupTimer>,
+    leaderboard: __sdk::TableAppliedDiff<'r, LeaderboardEntry>,
+    leaderboard_timer: __sdk::TableAppliedDiff<'r, LeaderboardTimer>,
     logged_out_player: __sdk::TableAppliedDiff<'r, Player>,
     move_all_players_timer: __sdk::TableAppliedDiff<'r, MoveAllPlayersTimer>,
     player: __sdk::TableAppliedDiff<'r, Player>,
+    player_stats: __sdk::TableAppliedDiff<'r, PlayerStats>,
+    rate_limit_bucket: __sdk::TableAppliedDiff<'r, RateLimitBucket>,
+    rate_limit_notice: __sdk::TableAppliedDiff<'r, RateLimitNotice>,
     spawn_food_timer: __sdk::TableAppliedDiff<'r, SpawnFoodTimer>,
+    world_tick: __sdk::TableAppliedDiff<'r, WorldTick>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -271,10 +713,45 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<Arena>("arena", &self.arena, event);
+        callbacks.invoke_table_row_callbacks::<BannedIdentity>(
+            "banned_identity",
+            &self.banned_identity,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Bot>("bot", &self.bot, event);
+        callbacks.invoke_table_row_callbacks::<BotTimer>("bot_timer", &self.bot_timer, event);
+        callbacks.invoke_table_row_callbacks::<ChatCleanupTimer>(
+            "chat_cleanup_timer",
+            &self.chat_cleanup_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_message",
+            &self.chat_message,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Circle>("circle", &self.circle, event);
         callbacks.invoke_table_row_callbacks::<Config>("config", &self.config, event);
         callbacks.invoke_table_row_callbacks::<Entity>("entity", &self.entity, event);
         callbacks.invoke_table_row_callbacks::<Food>("food", &self.food, event);
+        callbacks.invoke_table_row_callbacks::<KillEvent>("kill_event", &self.kill_event, event);
+        callbacks.invoke_table_row_callbacks::<KillEventCleanupTimer>(
+            "kill_event_cleanup_timer",
+            &self.kill_event_cleanup_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LeaderboardEntry>(
+            "leaderboard",
+            &self.leaderboard,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LeaderboardTimer>(
+            "leaderboard_timer",
+            &self.leaderboard_timer,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>(
             "logged_out_player",
             &self.logged_out_player,
@@ -286,11 +763,27 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             event,
         );
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<PlayerStats>(
+            "player_stats",
+            &self.player_stats,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RateLimitBucket>(
+            "rate_limit_bucket",
+            &self.rate_limit_bucket,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RateLimitNotice>(
+            "rate_limit_notice",
+            &self.rate_limit_notice,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<SpawnFoodTimer>(
             "spawn_food_timer",
             &self.spawn_food_timer,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<WorldTick>("world_tick", &self.world_tick, event);
     }
 }
 
@@ -866,13 +1359,28 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
+        arena_table::register_table(client_cache);
+        banned_identity_table::register_table(client_cache);
+        bot_table::register_table(client_cache);
+        bot_timer_table::register_table(client_cache);
+        chat_cleanup_timer_table::register_table(client_cache);
+        chat_message_table::register_table(client_cache);
         circle_table::register_table(client_cache);
         config_table::register_table(client_cache);
         entity_table::register_table(client_cache);
         food_table::register_table(client_cache);
+        kill_event_table::register_table(client_cache);
+        kill_event_cleanup_timer_table::register_table(client_cache);
+        leaderboard_table::register_table(client_cache);
+        leaderboard_timer_table::register_table(client_cache);
         logged_out_player_table::register_table(client_cache);
         move_all_players_timer_table::register_table(client_cache);
         player_table::register_table(client_cache);
+        player_stats_table::register_table(client_cache);
+        rate_limit_bucket_table::register_table(client_cache);
+        rate_limit_notice_table::register_table(client_cache);
         spawn_food_timer_table::register_table(client_cache);
+        world_tick_table::register_table(client_cache);
     }
 }
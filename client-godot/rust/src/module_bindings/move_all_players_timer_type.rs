@@ -11,6 +11,7 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub struct MoveAllPlayersTimer {
     pub scheduled_id: u64,
     pub scheduled_at: __sdk::ScheduleAt,
+    pub arena_id: u32,
 }
 
 impl __sdk::InModule for MoveAllPlayersTimer {
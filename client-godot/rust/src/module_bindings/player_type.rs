@@ -12,6 +12,7 @@ pub struct Player {
     pub identity: __sdk::Identity,
     pub player_id: u32,
     pub name: String,
+    pub arena_id: u32,
 }
 
 impl __sdk::InModule for Player {
@@ -0,0 +1,111 @@
+use super::*;
+use godot::classes::multi_mesh::TransformFormat;
+use godot::classes::{
+    IMultiMeshInstance2D, MultiMesh, MultiMeshInstance2D, QuadMesh, Shader, ShaderMaterial,
+};
+
+/// 每个实例占用的浮点数：2D 变换 8 个 + 颜色 4 个
+pub const FLOATS_PER_INSTANCE: usize = 12;
+
+/// MultiMesh 实例缓冲区
+///
+/// 与 `MultiMesh.buffer` 的布局一致，2D 变换按行存储：
+/// `[x.x, y.x, 0, origin.x, x.y, y.y, 0, origin.y, r, g, b, a]`
+#[derive(Default)]
+pub struct InstanceBuffer {
+    data: Vec<f32>,
+}
+
+impl InstanceBuffer {
+    /// 清空实例，保留已分配的容量
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// 添加一个以 `center` 为中心、直径为 `diameter` 的实例
+    pub fn push(&mut self, center: Vector2, diameter: f32, color: Color) {
+        self.data.extend_from_slice(&[
+            diameter, 0.0, 0.0, center.x, //
+            0.0, diameter, 0.0, center.y, //
+            color.r, color.g, color.b, color.a,
+        ]);
+    }
+
+    /// 实例数量
+    pub fn len(&self) -> usize {
+        self.data.len() / FLOATS_PER_INSTANCE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        &self.data
+    }
+}
+
+/// 基于 MultiMeshInstance2D 的实例化渲染器
+///
+/// 所有实例共用一个单位四边形和圆形着色器，一次绘制调用即可渲染
+#[derive(GodotClass)]
+#[class(init, base=MultiMeshInstance2D)]
+pub struct MultiMeshRenderer {
+    base: Base<MultiMeshInstance2D>,
+
+    /// 已分配的实例数量，按 2 的幂增长以减少重新分配
+    capacity: usize,
+
+    /// 上传给 MultiMesh 的缓冲区，长度为 `capacity * FLOATS_PER_INSTANCE`
+    staging: PackedFloat32Array,
+}
+
+#[godot_api]
+impl IMultiMeshInstance2D for MultiMeshRenderer {
+    fn ready(&mut self) {
+        self.ensure_multimesh();
+    }
+}
+
+impl MultiMeshRenderer {
+    const SHADER_PATH: &'static str = "res://instanced_entity_material.gdshader";
+
+    fn ensure_multimesh(&mut self) -> Gd<MultiMesh> {
+        if let Some(multimesh) = self.base().get_multimesh() {
+            return multimesh;
+        }
+
+        let mut quad = QuadMesh::new_gd();
+        quad.set_size(Vector2::ONE);
+
+        let mut multimesh = MultiMesh::new_gd();
+        // 必须在设置实例数量之前设置格式
+        multimesh.set_transform_format(TransformFormat::TRANSFORM_2D);
+        multimesh.set_use_colors(true);
+        multimesh.set_mesh(&quad);
+        self.base_mut().set_multimesh(&multimesh);
+
+        let mut material = ShaderMaterial::new_gd();
+        material.set_shader(&load::<Shader>(Self::SHADER_PATH));
+        self.base_mut().set_material(&material);
+
+        multimesh
+    }
+
+    /// 上传实例缓冲区，只显示其中的实例
+    pub fn upload(&mut self, instances: &InstanceBuffer) {
+        let mut multimesh = self.ensure_multimesh();
+
+        let count = instances.len();
+        if count > self.capacity {
+            self.capacity = count.next_power_of_two();
+            multimesh.set_instance_count(self.capacity as i32);
+            self.staging.resize(self.capacity * FLOATS_PER_INSTANCE);
+        }
+
+        self.staging.as_mut_slice()[..instances.as_slice().len()]
+            .copy_from_slice(instances.as_slice());
+        multimesh.set_buffer(&self.staging);
+        multimesh.set_visible_instance_count(count as i32);
+    }
+}
@@ -1,7 +1,10 @@
 use super::*;
 use crate::global_state::*;
+use crate::global_state::circle_batch_renderer;
+use crate::global_state::death_screen;
 use godot::classes::{InputEvent, InputEventKey, Label, Time};
 use godot::global::Key;
+use std::f32::consts::TAU;
 
 #[derive(GodotClass)]
 #[class(init, base=Node2D)]
@@ -15,10 +18,17 @@ pub struct PlayerController {
     #[init(val = 0.5)]
     sensitivity: real,
 
+    // Draws predicted (green) and authoritative (red) positions of the
+    // local circles. Can also be toggled in game with F3.
+    #[export]
+    debug_draw_prediction: bool,
+
     player_id: u32,
+    pub input_direction: Vector2,
     pub last_movement_send_timestamp: f32,
     pub lock_input_position: Option<Vector2>,
-    pub owned_circles: Vec<Gd<CircleController>>,
+    /// 圆形实体ID，圆形由 CircleBatchRenderer 渲染
+    pub owned_circles: Vec<u32>,
 }
 
 unsafe impl Send for PlayerController {}
@@ -39,6 +49,10 @@ impl PlayerController {
     const SEND_UPDATES_PER_SEC: i32 = 20;
     const SEND_UPDATES_FREQUENCY: f32 = 1.0 / (Self::SEND_UPDATES_PER_SEC as f32);
 
+    pub fn player_id(&self) -> u32 {
+        self.player_id
+    }
+
     pub fn username(&self) -> String {
         connection::get_connection()
             .unwrap()
@@ -75,20 +89,26 @@ impl PlayerController {
         }
     }
 
-    pub fn on_circle_spawned(&mut self, circle: Gd<CircleController>) {
-        self.owned_circles.push(circle);
+    pub fn on_circle_spawned(&mut self, entity_id: u32) {
+        self.owned_circles.push(entity_id);
+        if self.is_local_player() {
+            if let Some(mut screen) = death_screen::get_instance() {
+                screen.bind_mut().on_respawned();
+            }
+        }
     }
 
-    pub fn on_circle_deleted(&mut self, deleted_circle: Gd<CircleController>) {
+    pub fn on_circle_deleted(&mut self, entity_id: u32) {
         // This means we got eaten
-        if let Some(i) = self
-            .owned_circles
-            .iter()
-            .position(|x| x.eq(&deleted_circle))
-        {
+        if let Some(i) = self.owned_circles.iter().position(|&x| x == entity_id) {
             self.owned_circles.remove(i);
             if self.is_local_player() && self.owned_circles.len() == 0 {
-                // DeathScreen.Instance.SetVisible(true);}
+                events::push(GameEvent::LocalPlayerDied {
+                    player_id: self.player_id,
+                });
+                if let Some(mut screen) = death_screen::get_instance() {
+                    screen.bind_mut().show_death(self.player_id);
+                }
             }
         }
     }
@@ -97,8 +117,7 @@ impl PlayerController {
         let mass = self
             .owned_circles
             .iter()
-            .map(|x| {
-                let entity_id = x.bind().entity.entity_id;
+            .map(|&entity_id| {
                 if let Some(entity) = find_entity(entity_id) {
                     entity.mass
                 } else {
@@ -114,11 +133,14 @@ impl PlayerController {
             return None;
         }
 
+        let renderer = circle_batch_renderer::get_instance()?;
+        let renderer = renderer.bind();
         let mut total_pos = Vector2::ZERO;
         let mut total_mass = 0;
-        for circle in &self.owned_circles {
-            if let Some(entity) = find_entity(circle.bind().entity.entity_id) {
-                let position = circle.bind().base().get_position();
+        for &entity_id in &self.owned_circles {
+            if let (Some(entity), Some(position)) =
+                (find_entity(entity_id), renderer.circle_position(entity_id))
+            {
                 total_pos += position * entity.mass as f32;
                 total_mass += entity.mass;
             }
@@ -138,32 +160,73 @@ impl INode2D for PlayerController {
         let total_mass = self.total_mass();
         self.label.set_text(&format!("Total Mass: {}", total_mass));
 
+        // Sampled every frame so the predicted circles react immediately
+        let mouse_position = if let Some(pos) = self.lock_input_position {
+            pos
+        } else {
+            self.base().get_viewport().unwrap().get_mouse_position()
+        };
+        let screen_size = self.base().get_viewport_rect().size;
+        let screen_size = Vector2::new(screen_size.x as f32, screen_size.y as f32);
+        let center_of_screen = screen_size * 0.5;
+        self.input_direction =
+            (mouse_position - center_of_screen) / (screen_size.y / 3.0) * self.sensitivity;
+
         // Throttled input requests
-        let time = Time::singleton().get_ticks_msec() as f32;
+        let time = Time::singleton().get_ticks_msec() as f32 / 1000.0;
         if time - self.last_movement_send_timestamp > Self::SEND_UPDATES_FREQUENCY {
             self.last_movement_send_timestamp = time;
 
-            let mouse_position = if let Some(pos) = self.lock_input_position {
-                pos
-            } else {
-                self.base().get_viewport().unwrap().get_mouse_position()
-            };
-            let screen_size = self.base().get_viewport_rect().size;
-            let screen_size = Vector2::new(screen_size.x as f32, screen_size.y as f32);
-            let center_of_screen = screen_size * 0.5;
-
-            let direction = (mouse_position - center_of_screen) / (screen_size.y / 3.0) * self.sensitivity;
+            // 重连期间连接可能已经断开，丢弃这次输入，下一次发送会补上
             if let Some(conn) = connection::get_connection() {
-                conn.reducers.update_player_input(direction.into()).unwrap()
+                match conn.reducers.update_player_input(self.input_direction.into()) {
+                    Ok(()) => input_history::record_sent(self.input_direction),
+                    Err(e) => godot_warn!("Failed to send player input: {:?}", e),
+                }
             }
         }
+
+        if self.debug_draw_prediction {
+            // Drawn above the circles, which are added to the root later
+            self.base_mut().set_z_index(100);
+            self.base_mut().queue_redraw();
+        }
     }
 
-    fn exit_tree(&mut self) {
-        for circle in &mut self.owned_circles {
-            circle.queue_free();
+    fn draw(&mut self) {
+        if !self.debug_draw_prediction || !self.is_local_player() {
+            return;
         }
 
+        let Some(renderer) = circle_batch_renderer::get_instance() else {
+            return;
+        };
+        let renderer = renderer.bind();
+        let positions: Vec<(Vector2, Vector2, f32)> = self
+            .owned_circles
+            .iter()
+            .filter_map(|&entity_id| {
+                let prediction = renderer.circle_prediction(entity_id)?;
+                Some((
+                    prediction.position(),
+                    prediction.authoritative_position(),
+                    mass_to_radius(prediction.mass()),
+                ))
+            })
+            .collect();
+
+        for (predicted, authoritative, radius) in positions {
+            self.base_mut()
+                .draw_arc(predicted, radius, 0.0, TAU, 32, Color::GREEN);
+            self.base_mut()
+                .draw_arc(authoritative, radius, 0.0, TAU, 32, Color::RED);
+            self.base_mut()
+                .draw_line(authoritative, predicted, Color::YELLOW);
+        }
+    }
+
+    fn exit_tree(&mut self) {
+        // 圆形随实体删除从 CircleBatchRenderer 中移除
         self.owned_circles.clear();
     }
 
@@ -173,7 +236,29 @@ impl INode2D for PlayerController {
         }
 
         if let Ok(key) = event.try_cast::<InputEventKey>() {
-            if key.get_keycode() == Key::Q && !key.is_echo() {
+            if !key.is_pressed() || key.is_echo() {
+                return;
+            }
+
+            // Don't steal keys while typing in a text field, e.g. the chat
+            if self.base().get_viewport().unwrap().gui_get_focus_owner().is_some() {
+                return;
+            }
+
+            if key.get_keycode() == Key::SPACE {
+                if let Some(conn) = connection::get_connection() {
+                    if let Err(e) = conn.reducers.player_split() {
+                        godot_warn!("Failed to send player split: {:?}", e);
+                    }
+                }
+            }
+
+            if key.get_keycode() == Key::F3 {
+                self.debug_draw_prediction = !self.debug_draw_prediction;
+                self.base_mut().queue_redraw();
+            }
+
+            if key.get_keycode() == Key::Q {
                 if self.lock_input_position.is_some() {
                     self.lock_input_position = None;
                 } else {
@@ -0,0 +1,252 @@
+use super::*;
+use crate::camera_controller::WORLD_SIZE;
+use crate::global_state::*;
+use blackholio_sim as sim;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+
+/// 预测误差超过该距离时直接跳到服务器位置（例如分裂、被吞噬后的重生）
+const SNAP_DISTANCE: f32 = 50.0;
+/// 预测误差每秒衰减的速率
+const RECONCILE_RATE: f32 = 10.0;
+/// 最多向前预测的时间，避免服务器停止更新或延迟过高时圆形一直跑下去
+const MAX_PREDICTION_SEC: f64 = 0.5;
+/// 往返时间的平滑系数
+const RTT_SMOOTHING: f64 = 0.1;
+
+/// 与服务器一致的移动规则，只在 Config 变化时重建
+pub struct MovementRules {
+    rules: sim::Rules,
+    tick_interval_sec: f32,
+}
+
+impl MovementRules {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            rules: sim::Rules {
+                start_player_mass: config.start_player_mass,
+                start_player_speed: config.start_player_speed,
+                ..sim::Rules::default()
+            },
+            tick_interval_sec: config.move_all_players_interval_ms as f32 / 1000.0,
+        }
+    }
+
+    /// 服务器每个刻度移动 `direction * speed * mass_to_max_move_speed`，换算为每秒速度
+    fn velocity(&self, input: Vector2, mass: u32) -> Vector2 {
+        // 与服务器的 update_player_input 相同：方向归一化，速度限制在 [0, 1]
+        let magnitude = input.length();
+        if magnitude <= 0.0 || self.tick_interval_sec <= 0.0 {
+            return Vector2::ZERO;
+        }
+        let direction = input * (magnitude.min(1.0) / magnitude);
+        direction * self.rules.mass_to_max_move_speed(mass) / self.tick_interval_sec
+    }
+}
+
+fn clamp_to_world(position: Vector2, mass: u32) -> Vector2 {
+    let world_size = WORLD_SIZE.load(Ordering::Relaxed) as f32;
+    if world_size <= sim::mass_to_radius(mass) * 2.0 {
+        return position;
+    }
+    let clamped = sim::clamp_to_world(world_size, sim::Vector2::new(position.x, position.y), mass);
+    Vector2::new(clamped.x, clamped.y)
+}
+
+/// 本地玩家发送的输入和测得的往返时间
+///
+/// 输入按发送时间记录，服务器确认（reducer 回调）时测量往返时间。
+/// 服务器快照到达时已经过了大约一个往返时间，这段时间内发送的输入服务器还没有应用
+#[derive(Default)]
+pub struct InputHistory {
+    /// (本地发送时间, 输入)，按时间排序
+    sent: VecDeque<(f64, Vector2)>,
+    /// 还没有被服务器确认的输入的发送时间
+    unacknowledged: VecDeque<f64>,
+    rtt: Option<f64>,
+}
+
+impl InputHistory {
+    /// 记录发送的输入，丢弃预测窗口之外的旧输入（保留窗口开始时生效的那一个）
+    pub fn on_sent(&mut self, time: f64, input: Vector2) {
+        self.sent.push_back((time, input));
+        self.unacknowledged.push_back(time);
+
+        let cutoff = time - MAX_PREDICTION_SEC - self.rtt();
+        while self.sent.len() > 1 && self.sent[1].0 <= cutoff {
+            self.sent.pop_front();
+        }
+    }
+
+    /// 服务器确认了最早的未确认输入
+    pub fn on_acknowledged(&mut self, time: f64) {
+        let Some(sent_at) = self.unacknowledged.pop_front() else {
+            return;
+        };
+        let sample = (time - sent_at).max(0.0);
+        self.rtt = Some(match self.rtt {
+            Some(rtt) => rtt + (sample - rtt) * RTT_SMOOTHING,
+            None => sample,
+        });
+    }
+
+    /// 平滑后的往返时间（秒），还没有测量时为 0
+    pub fn rtt(&self) -> f64 {
+        self.rtt.unwrap_or(0.0)
+    }
+
+    /// 还没有被服务器确认的输入数量
+    pub fn pending_count(&self) -> usize {
+        self.unacknowledged.len()
+    }
+
+    /// 最后一次发送的输入
+    pub fn last_input(&self) -> Vector2 {
+        self.sent.back().map_or(Vector2::ZERO, |&(_, input)| input)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 按 `[from, to]` 内各时刻发送的输入积分位移，最后一次发送之后使用 `current`
+    pub fn displacement<F>(&self, from: f64, to: f64, current: Vector2, velocity: F) -> Vector2
+    where
+        F: Fn(Vector2) -> Vector2,
+    {
+        let mut displacement = Vector2::ZERO;
+        let mut time = from;
+        let mut input = Vector2::ZERO;
+        for &(sent_at, sent_input) in &self.sent {
+            if sent_at > time {
+                let end = sent_at.min(to);
+                displacement += velocity(input) * (end - time) as f32;
+                time = end;
+            }
+            input = sent_input;
+            if time >= to {
+                return displacement;
+            }
+        }
+        displacement + velocity(current) * (to - time).max(0.0) as f32
+    }
+}
+
+/// 本地玩家圆形的客户端预测
+///
+/// 服务器位置到达时，服务器还没有应用最近一个往返时间内发送的输入，而现在发送的输入
+/// 还要半个往返时间才能到达。因此从服务器位置出发，按“到达时间 - 往返时间”到现在
+/// 发送过的输入积分，得到服务器应用完这些输入后的位置；
+/// 收到新的服务器位置时，把与旧显示位置的差值作为修正量逐渐衰减，避免跳变
+pub struct CirclePrediction {
+    authoritative_position: Vector2,
+    /// 收到服务器位置的本地时间
+    received_at: f64,
+    mass: u32,
+    correction: Vector2,
+    position: Vector2,
+}
+
+impl CirclePrediction {
+    pub fn new(entity: &Entity) -> Self {
+        let position = (&entity.position).into();
+        Self {
+            authoritative_position: position,
+            received_at: interpolation::local_time(),
+            mass: entity.mass,
+            correction: Vector2::ZERO,
+            position,
+        }
+    }
+
+    /// 服务器位置
+    pub fn authoritative_position(&self) -> Vector2 {
+        self.authoritative_position
+    }
+
+    /// 预测位置
+    pub fn position(&self) -> Vector2 {
+        self.position
+    }
+
+    pub fn mass(&self) -> u32 {
+        self.mass
+    }
+
+    fn predicted_position(&self, now: f64, input: Vector2) -> Vector2 {
+        let Some(velocity) = movement::with_rules(|rules| {
+            input_history::with(|history| {
+                let from = (self.received_at - history.rtt()).max(now - MAX_PREDICTION_SEC);
+                history.displacement(from, now, input, |x| rules.velocity(x, self.mass))
+            })
+        }) else {
+            return self.authoritative_position;
+        };
+        clamp_to_world(self.authoritative_position + velocity, self.mass)
+    }
+
+    /// 收到服务器的实体更新
+    pub fn on_authoritative_update(&mut self, entity: &Entity) {
+        self.authoritative_position = (&entity.position).into();
+        self.received_at = interpolation::local_time();
+        self.mass = entity.mass;
+
+        let input = input_history::with(|history| history.last_input());
+        let correction = self.position - self.predicted_position(self.received_at, input);
+        self.correction = if correction.length() > SNAP_DISTANCE {
+            Vector2::ZERO
+        } else {
+            correction
+        };
+    }
+
+    /// 按本地输入推进预测，返回新的显示位置
+    pub fn update(&mut self, input: Vector2, delta: f32) -> Vector2 {
+        self.correction *= (-RECONCILE_RATE * delta).exp();
+        self.position =
+            self.predicted_position(interpolation::local_time(), input) + self.correction;
+        self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn velocity(input: Vector2) -> Vector2 {
+        input * 10.0
+    }
+
+    #[test]
+    fn acknowledgements_measure_round_trip_time() {
+        let mut history = InputHistory::default();
+        history.on_sent(1.0, Vector2::RIGHT);
+        history.on_sent(1.05, Vector2::RIGHT);
+        assert_eq!(history.pending_count(), 2);
+
+        history.on_acknowledged(1.2);
+        assert!((history.rtt() - 0.2).abs() < 1e-9);
+        assert_eq!(history.pending_count(), 1);
+
+        // 后续样本平滑合并
+        history.on_acknowledged(1.35);
+        assert!((history.rtt() - (0.2 + (0.3 - 0.2) * RTT_SMOOTHING)).abs() < 1e-9);
+        assert_eq!(history.pending_count(), 0);
+    }
+
+    #[test]
+    fn displacement_follows_the_inputs_sent_in_the_window() {
+        let mut history = InputHistory::default();
+        history.on_sent(0.0, Vector2::RIGHT);
+        history.on_sent(1.0, Vector2::UP);
+
+        // 0.5 秒向右，0.5 秒向上，发送之后的 0.25 秒使用当前输入
+        let displacement = history.displacement(0.5, 1.75, Vector2::LEFT, velocity);
+        let expected = Vector2::RIGHT * 5.0 + Vector2::UP * 5.0 + Vector2::LEFT * 2.5;
+        assert!((displacement - expected).length() < 1e-4);
+
+        // 窗口在两次发送之间结束
+        let displacement = history.displacement(0.25, 0.75, Vector2::LEFT, velocity);
+        assert!((displacement - Vector2::RIGHT * 5.0).length() < 1e-4);
+    }
+}
@@ -6,8 +6,6 @@ use crate::global_state::prefab_state;
 pub struct PrefabManager {
     base: Base<Node>,
 
-    #[export]
-    circle_prefab: Option<Gd<PackedScene>>,
     #[export]
     player_prefab: Option<Gd<PackedScene>>,
 }
@@ -21,31 +19,7 @@ impl INode for PrefabManager {
     }
 }
 
-pub fn spawn_circle(circle: Circle, mut owner: Gd<PlayerController>) -> Gd<CircleController> {
-    let mut entity_controller = prefab_state::get_instance()
-        .expect("PrefabManager instance not found")
-        .bind()
-        .circle_prefab
-        .clone()
-        .unwrap()
-        .instantiate()
-        .unwrap()
-        .cast::<CircleController>();
-
-    entity_controller
-        .bind_mut()
-        .base_mut()
-        .set_name(&format!("Circle - {}", circle.entity_id));
-    owner
-        .bind_mut()
-        .on_circle_spawned(entity_controller.clone());
-    get_root().unwrap().add_child(&entity_controller);
-    entity_controller.bind_mut().spawn(circle.clone(), owner.clone());
-
-    entity_controller
-}
-
-// spawn_food 函数已移除，现在使用 FoodBatchRenderer 进行批量渲染
+// spawn_food 和 spawn_circle 函数已移除，现在使用 FoodBatchRenderer 和 CircleBatchRenderer 进行批量渲染
 
 pub fn spawn_player(player: Player) -> Gd<PlayerController> {
     let mut entity_controller = prefab_state::get_instance()
@@ -0,0 +1,96 @@
+use super::*;
+use crate::global_state::*;
+use std::collections::VecDeque;
+
+/// 服务器状态快照
+#[derive(Clone, Copy)]
+pub struct Snapshot {
+    /// 服务器时间（秒）
+    pub time: f64,
+    /// 位置
+    pub position: Vector2,
+    /// 缩放
+    pub scale: Vector2,
+}
+
+/// 单个实体的快照缓冲区
+///
+/// 以略早于当前服务器时间的渲染时间采样，在前后两个快照之间插值；
+/// 丢包导致没有更新的快照时，沿最后的速度短暂外推
+#[derive(Clone, Default)]
+pub struct SnapshotBuffer {
+    snapshots: VecDeque<Snapshot>,
+}
+
+impl SnapshotBuffer {
+    const MAX_SNAPSHOTS: usize = 32;
+    const MAX_EXTRAPOLATION_SEC: f64 = 0.25;
+
+    /// 记录一个新的快照，同一服务器时间的快照会替换旧的
+    pub fn push(&mut self, time: f64, position: Vector2, scale: Vector2) {
+        let snapshot = Snapshot {
+            time,
+            position,
+            scale,
+        };
+        match self.snapshots.back_mut() {
+            Some(last) if time <= last.time => *last = snapshot,
+            _ => self.snapshots.push_back(snapshot),
+        }
+
+        if self.snapshots.len() > Self::MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// 渲染时间已过最新快照和外推时间，之后的采样结果不会再变化
+    pub fn is_settled(&self, render_time: f64) -> bool {
+        match self.snapshots.back() {
+            Some(last) if self.snapshots.len() > 1 => {
+                render_time >= last.time + Self::MAX_EXTRAPOLATION_SEC
+            }
+            _ => true,
+        }
+    }
+
+    /// 获取指定渲染时间的位置和缩放
+    pub fn sample(&mut self, render_time: f64) -> Option<(Vector2, Vector2)> {
+        // 丢弃渲染时间之前不再需要的快照，保留一个作为插值起点
+        while self.snapshots.len() > 2 && self.snapshots[1].time <= render_time {
+            self.snapshots.pop_front();
+        }
+
+        let first = *self.snapshots.front()?;
+        if render_time <= first.time || self.snapshots.len() == 1 {
+            return Some((first.position, first.scale));
+        }
+
+        let second = self.snapshots[1];
+        if render_time <= second.time {
+            let t = ((render_time - first.time) / (second.time - first.time)) as f32;
+            return Some((
+                first.position.lerp(second.position, t),
+                first.scale.lerp(second.scale, t),
+            ));
+        }
+
+        // 渲染时间已超过最新快照，按最后两个快照之间的速度外推
+        let elapsed = f64::min(render_time - second.time, Self::MAX_EXTRAPOLATION_SEC);
+        let velocity = (second.position - first.position) / (second.time - first.time) as f32;
+        Some((second.position + velocity * elapsed as f32, second.scale))
+    }
+}
+
+/// 实体所属竞技场的最新服务器时间（秒）
+///
+/// 实体更新与世界刻度在同一事务中提交，因此刻度时间就是该快照的时间
+pub fn snapshot_time(entity: &Entity) -> f64 {
+    connection::get_connection()
+        .and_then(|conn| conn.db.world_tick().arena_id().find(&entity.arena_id))
+        .map(|world_tick| timestamp_to_secs(world_tick.timestamp))
+        .unwrap_or_else(interpolation::server_time)
+}
+
+pub fn timestamp_to_secs(timestamp: spacetimedb_sdk::Timestamp) -> f64 {
+    timestamp.to_micros_since_unix_epoch() as f64 / 1_000_000.0
+}
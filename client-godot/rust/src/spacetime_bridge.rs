@@ -0,0 +1,295 @@
+use super::*;
+use crate::global_state::*;
+
+/// 供 GDScript 读取表数据和调用 reducer 的桥接类
+///
+/// 行数据转换为 Dictionary，查询不到或未连接时返回空的 Dictionary/Array。
+/// reducer 方法只表示请求是否已发送。这里的每个 reducer 被服务器拒绝时，
+/// GameManager 都会发出 `on_reducer_error`；输入被限流时服务器会直接丢弃，不算拒绝
+///
+/// ```gdscript
+/// var bridge = SpacetimeBridge.new()
+/// for player in bridge.get_players():
+///     print(player.name)
+/// ```
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+pub struct SpacetimeBridge {
+    base: Base<RefCounted>,
+}
+
+fn player_to_dict(player: &Player) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("identity", player.identity.to_hex().to_string());
+    dict.set("player_id", player.player_id);
+    dict.set("name", player.name.as_str());
+    dict.set("arena_id", player.arena_id);
+    dict
+}
+
+fn entity_to_dict(entity: &Entity) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("entity_id", entity.entity_id);
+    dict.set("arena_id", entity.arena_id);
+    dict.set("position", Vector2::from(&entity.position));
+    dict.set("mass", entity.mass);
+    dict
+}
+
+/// 圆圈与其实体合并为一个 Dictionary
+fn circle_to_dict(circle: &Circle, entity: Option<&Entity>) -> Dictionary {
+    let mut dict = entity.map(entity_to_dict).unwrap_or_default();
+    dict.set("entity_id", circle.entity_id);
+    dict.set("player_id", circle.player_id);
+    dict.set("direction", Vector2::from(&circle.direction));
+    dict.set("speed", circle.speed);
+    dict.set("last_split_time", timestamp_to_secs(circle.last_split_time));
+    dict.set("impulse", Vector2::from(&circle.impulse));
+    dict
+}
+
+fn arena_to_dict(arena: &Arena) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("arena_id", arena.arena_id);
+    dict.set("name", arena.name.as_str());
+    dict.set("world_size", arena.world_size as i64);
+    dict.set("target_food_count", arena.target_food_count);
+    dict.set("max_players", arena.max_players);
+    dict.set("player_count", arena.player_count);
+    dict
+}
+
+fn config_to_dict(config: &Config) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("food_mass_min", config.food_mass_min);
+    dict.set("food_mass_max", config.food_mass_max);
+    dict.set("start_player_mass", config.start_player_mass);
+    dict.set("start_player_speed", config.start_player_speed);
+    dict.set("minimum_safe_mass_ratio", config.minimum_safe_mass_ratio);
+    dict.set("min_mass_to_split", config.min_mass_to_split);
+    dict.set("max_circles_per_player", config.max_circles_per_player);
+    dict.set("split_cooldown_ms", config.split_cooldown_ms as i64);
+    dict.set("split_recombine_delay_ms", config.split_recombine_delay_ms as i64);
+    dict.set("spawn_food_interval_ms", config.spawn_food_interval_ms as i64);
+    dict.set("move_all_players_interval_ms", config.move_all_players_interval_ms as i64);
+    dict.set("min_arena_population", config.min_arena_population);
+    dict
+}
+
+fn leaderboard_entry_to_dict(entry: &LeaderboardEntry) -> Dictionary {
+    let mut dict = Dictionary::new();
+    dict.set("arena_id", entry.arena_id);
+    dict.set("rank", entry.rank);
+    dict.set("player_id", entry.player_id);
+    dict.set("name", entry.name.as_str());
+    dict.set("total_mass", entry.total_mass);
+    dict
+}
+
+/// 发送 reducer 请求，失败时记录错误
+fn send_reducer<F>(name: &str, call: F) -> bool
+where
+    F: FnOnce(&DbConnection) -> spacetimedb_sdk::Result<()>,
+{
+    let Some(conn) = connection::get_connection() else {
+        godot_error!("Cannot call {}: not connected", name);
+        return false;
+    };
+    match call(&conn) {
+        Ok(()) => true,
+        Err(e) => {
+            godot_error!("Failed to call {}: {}", name, e);
+            false
+        }
+    }
+}
+
+#[godot_api]
+impl SpacetimeBridge {
+    /// 是否已连接
+    #[func]
+    fn is_connected_to_server(&self) -> bool {
+        connection::is_connected()
+    }
+
+    /// 订阅数据是否已就绪
+    #[func]
+    fn is_ready(&self) -> bool {
+        connection::status() == ConnectionStatus::Ready
+    }
+
+    /// 本地身份的十六进制字符串，未连接时为空
+    #[func]
+    fn get_local_identity(&self) -> GString {
+        identity::get_local_identity()
+            .map(|x| GString::from(x.to_hex().to_string()))
+            .unwrap_or_default()
+    }
+
+    /// 本地玩家
+    #[func]
+    fn get_local_player(&self) -> Dictionary {
+        let (Some(conn), Some(local_identity)) =
+            (connection::get_connection(), identity::get_local_identity())
+        else {
+            return Dictionary::new();
+        };
+        conn.db
+            .player()
+            .identity()
+            .find(&local_identity)
+            .map(|x| player_to_dict(&x))
+            .unwrap_or_default()
+    }
+
+    /// 所有在线玩家
+    #[func]
+    fn get_players(&self) -> Array<Dictionary> {
+        let Some(conn) = connection::get_connection() else {
+            return Array::new();
+        };
+        conn.db.player().iter().map(|x| player_to_dict(&x)).collect()
+    }
+
+    #[func]
+    fn get_player(&self, player_id: u32) -> Dictionary {
+        let Some(conn) = connection::get_connection() else {
+            return Dictionary::new();
+        };
+        conn.db
+            .player()
+            .player_id()
+            .find(&player_id)
+            .map(|x| player_to_dict(&x))
+            .unwrap_or_default()
+    }
+
+    #[func]
+    fn get_entity(&self, entity_id: u32) -> Dictionary {
+        let Some(conn) = connection::get_connection() else {
+            return Dictionary::new();
+        };
+        conn.db
+            .entity()
+            .entity_id()
+            .find(&entity_id)
+            .map(|x| entity_to_dict(&x))
+            .unwrap_or_default()
+    }
+
+    /// 玩家的所有圆圈，包含实体的位置和质量
+    #[func]
+    fn get_circles(&self, player_id: u32) -> Array<Dictionary> {
+        let Some(conn) = connection::get_connection() else {
+            return Array::new();
+        };
+        conn.db
+            .circle()
+            .iter()
+            .filter(|x| x.player_id == player_id)
+            .map(|circle| {
+                let entity = conn.db.entity().entity_id().find(&circle.entity_id);
+                circle_to_dict(&circle, entity.as_ref())
+            })
+            .collect()
+    }
+
+    /// 竞技场中的食物数量
+    #[func]
+    fn get_food_count(&self, arena_id: u32) -> i64 {
+        let Some(conn) = connection::get_connection() else {
+            return 0;
+        };
+        conn.db
+            .food()
+            .iter()
+            .filter(|food| {
+                conn.db
+                    .entity()
+                    .entity_id()
+                    .find(&food.entity_id)
+                    .is_some_and(|x| x.arena_id == arena_id)
+            })
+            .count() as i64
+    }
+
+    #[func]
+    fn get_arena(&self, arena_id: u32) -> Dictionary {
+        let Some(conn) = connection::get_connection() else {
+            return Dictionary::new();
+        };
+        conn.db
+            .arena()
+            .arena_id()
+            .find(&arena_id)
+            .map(|x| arena_to_dict(&x))
+            .unwrap_or_default()
+    }
+
+    #[func]
+    fn get_arenas(&self) -> Array<Dictionary> {
+        let Some(conn) = connection::get_connection() else {
+            return Array::new();
+        };
+        conn.db.arena().iter().map(|x| arena_to_dict(&x)).collect()
+    }
+
+    /// 竞技场的排行榜，按名次排序
+    #[func]
+    fn get_leaderboard(&self, arena_id: u32) -> Array<Dictionary> {
+        let Some(conn) = connection::get_connection() else {
+            return Array::new();
+        };
+        let mut entries: Vec<LeaderboardEntry> = conn
+            .db
+            .leaderboard()
+            .iter()
+            .filter(|x| x.arena_id == arena_id)
+            .collect();
+        entries.sort_by_key(|x| x.rank);
+        entries.iter().map(leaderboard_entry_to_dict).collect()
+    }
+
+    #[func]
+    fn get_config(&self) -> Dictionary {
+        let Some(conn) = connection::get_connection() else {
+            return Dictionary::new();
+        };
+        conn.db
+            .config()
+            .id()
+            .find(&0)
+            .map(|x| config_to_dict(&x))
+            .unwrap_or_default()
+    }
+
+    #[func]
+    fn enter_game(&self, name: GString) -> bool {
+        send_reducer("enter_game", |conn| {
+            conn.reducers.enter_game(name.to_string())
+        })
+    }
+
+    #[func]
+    fn respawn(&self) -> bool {
+        send_reducer("respawn", |conn| conn.reducers.respawn())
+    }
+
+    /// 设置移动方向，长度即速度比例，与玩家控制器发送的相同
+    #[func]
+    fn update_player_input(&self, direction: Vector2) -> bool {
+        send_reducer("update_player_input", |conn| {
+            conn.reducers.update_player_input(direction.into())
+        })
+    }
+
+    #[func]
+    fn player_split(&self) -> bool {
+        send_reducer("player_split", |conn| conn.reducers.player_split())
+    }
+
+    #[func]
+    fn send_chat(&self, text: GString) -> bool {
+        send_reducer("send_chat", |conn| conn.reducers.send_chat(text.to_string()))
+    }
+}
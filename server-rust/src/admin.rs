@@ -0,0 +1,177 @@
+use super::*;
+
+// Optionally grant admin to another identity at build time, e.g.
+// `BLACKHOLIO_ADMIN_IDENTITY=c200... spacetime publish`.
+// The identity that publishes the module is always an admin.
+const CONFIGURED_ADMIN_IDENTITY: Option<&str> = option_env!("BLACKHOLIO_ADMIN_IDENTITY");
+
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+}
+
+#[spacetimedb::table(name = banned_identity)]
+pub struct BannedIdentity {
+    #[primary_key]
+    pub identity: Identity,
+    pub reason: String,
+    pub banned_at: Timestamp,
+}
+
+pub fn seed_admins(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.admin().try_insert(Admin {
+        identity: ctx.sender,
+    })?;
+
+    if let Some(hex) = CONFIGURED_ADMIN_IDENTITY {
+        let identity = Identity::from_hex(hex.trim())
+            .map_err(|e| format!("Invalid BLACKHOLIO_ADMIN_IDENTITY: {e}"))?;
+        if identity != ctx.sender {
+            ctx.db.admin().try_insert(Admin { identity })?;
+        }
+    }
+    Ok(())
+}
+
+pub fn is_admin(ctx: &ReducerContext) -> bool {
+    ctx.db.admin().identity().find(&ctx.sender).is_some()
+}
+
+pub fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if is_admin(ctx) {
+        Ok(())
+    } else {
+        Err("Only admins can do this".into())
+    }
+}
+
+// Scheduled reducers are public like any other, so clients could call them
+// directly. Only the module itself, through the scheduler, may run them.
+pub fn require_scheduler(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.sender == ctx.identity() {
+        Ok(())
+    } else {
+        Err("Only the scheduler can call this".into())
+    }
+}
+
+pub fn is_banned(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db
+        .banned_identity()
+        .identity()
+        .find(&identity)
+        .is_some()
+}
+
+fn delete_player_circles(ctx: &ReducerContext, player_id: u32) {
+    for circle in ctx.db.circle().player_id().filter(&player_id) {
+        ctx.db.entity().entity_id().delete(&circle.entity_id);
+        ctx.db.circle().entity_id().delete(&circle.entity_id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if ctx.db.admin().identity().find(&identity).is_none() {
+        ctx.db.admin().insert(Admin { identity });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    if identity == ctx.sender {
+        return Err("Admins cannot remove themselves".into());
+    }
+    ctx.db.admin().identity().delete(&identity);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn kick_player(ctx: &ReducerContext, player_id: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    let player = ctx
+        .db
+        .player()
+        .player_id()
+        .find(&player_id)
+        .ok_or("Player not found")?;
+    log::info!("Player {} kicked by {}", player.name, ctx.sender);
+
+    delete_player_circles(ctx, player_id);
+    end_player_session(ctx, player.identity);
+    ctx.db.player().identity().delete(&player.identity);
+    refresh_arena_player_count(ctx, player.arena_id);
+    ctx.db.logged_out_player().insert(player);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ban_identity(
+    ctx: &ReducerContext,
+    identity: Identity,
+    reason: String,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    if identity == ctx.sender {
+        return Err("Admins cannot ban themselves".into());
+    }
+
+    ctx.db.banned_identity().identity().delete(&identity);
+    ctx.db.banned_identity().insert(BannedIdentity {
+        identity,
+        reason,
+        banned_at: ctx.timestamp,
+    });
+
+    // Take the player out of the arena right away if they are online
+    if let Some(player) = ctx.db.player().identity().find(&identity) {
+        delete_player_circles(ctx, player.player_id);
+        end_player_session(ctx, identity);
+        ctx.db.player().identity().delete(&identity);
+        refresh_arena_player_count(ctx, player.arena_id);
+        ctx.db.logged_out_player().insert(player);
+    }
+    log::info!("Identity {} banned by {}", identity, ctx.sender);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unban_identity(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+    ctx.db.banned_identity().identity().delete(&identity);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn rename_player(ctx: &ReducerContext, player_id: u32, name: String) -> Result<(), String> {
+    require_admin(ctx)?;
+    let mut player = ctx
+        .db
+        .player()
+        .player_id()
+        .find(&player_id)
+        .ok_or("Player not found")?;
+    let name = validate_name(ctx, player_id, &name)?;
+    log::info!(
+        "Player {} renamed to {} by {}",
+        player.name,
+        name,
+        ctx.sender
+    );
+    player.name = name;
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clear_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    let arena = get_arena(ctx, arena_id)?;
+    delete_arena_entities(ctx, arena_id);
+    log::info!("Arena {} cleared by {}", arena.name, ctx.sender);
+    Ok(())
+}
@@ -0,0 +1,223 @@
+use super::*;
+
+const DEFAULT_ARENA_NAME: &str = "Main";
+const DEFAULT_WORLD_SIZE: u64 = 1000;
+const DEFAULT_TARGET_FOOD_COUNT: u32 = 600;
+const DEFAULT_MAX_PLAYERS: u32 = 50;
+
+const MIN_WORLD_SIZE: u64 = 100;
+const MAX_WORLD_SIZE: u64 = 100_000;
+const MAX_TARGET_FOOD_COUNT: u32 = 100_000;
+
+// An independent match with its own world, food and timers.
+// `player_count` is the number of online players assigned to it.
+#[spacetimedb::table(name = arena, public)]
+pub struct Arena {
+    #[primary_key]
+    #[auto_inc]
+    pub arena_id: u32,
+    pub name: String,
+    pub world_size: u64,
+    pub target_food_count: u32,
+    pub max_players: u32,
+    pub player_count: u32,
+}
+
+// The simulation step an arena is on, bumped by every `move_all_players` run.
+// Entity updates committed together with a tick belong to that step.
+#[spacetimedb::table(name = world_tick, public)]
+pub struct WorldTick {
+    #[primary_key]
+    pub arena_id: u32,
+    pub tick: u64,
+    pub timestamp: Timestamp,
+}
+
+pub fn advance_world_tick(ctx: &ReducerContext, arena_id: u32) {
+    match ctx.db.world_tick().arena_id().find(&arena_id) {
+        Some(mut world_tick) => {
+            world_tick.tick += 1;
+            world_tick.timestamp = ctx.timestamp;
+            ctx.db.world_tick().arena_id().update(world_tick);
+        }
+        None => {
+            ctx.db.world_tick().insert(WorldTick {
+                arena_id,
+                tick: 0,
+                timestamp: ctx.timestamp,
+            });
+        }
+    }
+}
+
+pub fn get_arena(ctx: &ReducerContext, arena_id: u32) -> Result<Arena, String> {
+    ctx.db
+        .arena()
+        .arena_id()
+        .find(&arena_id)
+        .ok_or_else(|| format!("Arena {arena_id} not found"))
+}
+
+fn insert_arena(
+    ctx: &ReducerContext,
+    name: String,
+    world_size: u64,
+    target_food_count: u32,
+    max_players: u32,
+) -> Result<Arena, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Arena name must not be empty".into());
+    }
+    if !(MIN_WORLD_SIZE..=MAX_WORLD_SIZE).contains(&world_size) {
+        return Err(format!(
+            "world_size must be between {MIN_WORLD_SIZE} and {MAX_WORLD_SIZE}"
+        ));
+    }
+    if target_food_count > MAX_TARGET_FOOD_COUNT {
+        return Err(format!(
+            "target_food_count must not exceed {MAX_TARGET_FOOD_COUNT}"
+        ));
+    }
+    if max_players == 0 {
+        return Err("max_players must be at least 1".into());
+    }
+    let config = get_config(ctx)?;
+    config.check_fits_world(world_size)?;
+
+    let arena = ctx.db.arena().try_insert(Arena {
+        arena_id: 0,
+        name,
+        world_size,
+        target_food_count,
+        max_players,
+        player_count: 0,
+    })?;
+    advance_world_tick(ctx, arena.arena_id);
+    schedule_spawn_food(ctx, arena.arena_id, config.spawn_food_interval_ms)?;
+    schedule_move_all_players(ctx, arena.arena_id, config.move_all_players_interval_ms)?;
+    log::info!("Created arena {} ({})", arena.name, arena.arena_id);
+    Ok(arena)
+}
+
+pub fn create_default_arena(ctx: &ReducerContext) -> Result<Arena, String> {
+    insert_arena(
+        ctx,
+        DEFAULT_ARENA_NAME.to_string(),
+        DEFAULT_WORLD_SIZE,
+        DEFAULT_TARGET_FOOD_COUNT,
+        DEFAULT_MAX_PLAYERS,
+    )
+}
+
+pub fn players_in_arena(ctx: &ReducerContext, arena_id: u32) -> u32 {
+    ctx.db.player().arena_id().filter(&arena_id).count() as u32
+}
+
+// Managed bots only fill empty seats, so they are left out when checking
+// whether an arena has room. `make_room_for_player` despawns one when needed.
+pub fn has_room(ctx: &ReducerContext, arena: &Arena) -> bool {
+    let managed_bots = managed_bots_in_arena(ctx, arena.arena_id).len() as u32;
+    players_in_arena(ctx, arena.arena_id) - managed_bots < arena.max_players
+}
+
+pub fn refresh_arena_player_count(ctx: &ReducerContext, arena_id: u32) {
+    if let Some(mut arena) = ctx.db.arena().arena_id().find(&arena_id) {
+        arena.player_count = players_in_arena(ctx, arena_id);
+        ctx.db.arena().arena_id().update(arena);
+    }
+}
+
+// The oldest arena that still has room. When every arena is full, an
+// overflow arena with the default settings is created.
+pub fn find_open_arena(ctx: &ReducerContext) -> Result<u32, String> {
+    let mut arenas: Vec<Arena> = ctx.db.arena().iter().collect();
+    arenas.sort_by_key(|arena| arena.arena_id);
+    if let Some(arena) = arenas
+        .iter()
+        .find(|arena| has_room(ctx, arena))
+    {
+        return Ok(arena.arena_id);
+    }
+
+    let arena = insert_arena(
+        ctx,
+        format!("{DEFAULT_ARENA_NAME} {}", arenas.len() + 1),
+        DEFAULT_WORLD_SIZE,
+        DEFAULT_TARGET_FOOD_COUNT,
+        DEFAULT_MAX_PLAYERS,
+    )?;
+    Ok(arena.arena_id)
+}
+
+pub fn delete_arena_entities(ctx: &ReducerContext, arena_id: u32) {
+    for entity in ctx.db.entity().arena_id().filter(&arena_id) {
+        ctx.db.circle().entity_id().delete(&entity.entity_id);
+        ctx.db.food().entity_id().delete(&entity.entity_id);
+        ctx.db.entity().entity_id().delete(&entity.entity_id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn join_arena(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    let mut player = ctx
+        .db
+        .player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+    if player.arena_id == arena_id {
+        return Ok(());
+    }
+
+    let arena = get_arena(ctx, arena_id)?;
+    if !has_room(ctx, &arena) {
+        return Err(format!("Arena {} is full", arena.name));
+    }
+
+    // Leaving forfeits whatever the player had in their old arena
+    for circle in ctx.db.circle().player_id().filter(&player.player_id) {
+        ctx.db.entity().entity_id().delete(&circle.entity_id);
+        ctx.db.circle().entity_id().delete(&circle.entity_id);
+    }
+    end_player_session(ctx, ctx.sender);
+
+    let old_arena_id = player.arena_id;
+    player.arena_id = arena_id;
+    log::info!("Player {} joined arena {}", player.name, arena.name);
+    ctx.db.player().identity().update(player);
+    refresh_arena_player_count(ctx, old_arena_id);
+    make_room_for_player(ctx, &arena);
+    refresh_arena_player_count(ctx, arena_id);
+    Ok(())
+}
+
+// Reducers cannot return data, so clients read the public `arena` table.
+// This recounts the players of every arena so that table is up to date.
+#[spacetimedb::reducer]
+pub fn list_arenas(ctx: &ReducerContext) -> Result<(), String> {
+    for arena in ctx.db.arena().iter() {
+        refresh_arena_player_count(ctx, arena.arena_id);
+        log::info!(
+            "Arena {} ({}): {}/{} players",
+            arena.name,
+            arena.arena_id,
+            players_in_arena(ctx, arena.arena_id),
+            arena.max_players
+        );
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn create_arena(
+    ctx: &ReducerContext,
+    name: String,
+    world_size: u64,
+    target_food_count: u32,
+    max_players: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+    insert_arena(ctx, name, world_size, target_food_count, max_players)?;
+    Ok(())
+}
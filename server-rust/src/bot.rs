@@ -0,0 +1,172 @@
+use super::*;
+use std::collections::HashMap;
+
+const BOT_INTERVAL_MS: u64 = 200;
+
+// A server-driven player. Every bot has a regular `player` row with a made-up
+// identity, so leaderboards and kill events treat it like anyone else.
+// `managed` bots are added and removed automatically to keep each arena at
+// `Config::min_arena_population`, and give up their seat when a player needs it;
+// bots added by an admin take a seat like a player and stay until removed.
+#[spacetimedb::table(name = bot, public)]
+pub struct Bot {
+    #[primary_key]
+    #[auto_inc]
+    pub bot_id: u32,
+    #[unique]
+    pub player_id: u32,
+    pub managed: bool,
+}
+
+#[spacetimedb::table(name = bot_timer, scheduled(update_bots))]
+pub struct BotTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub fn schedule_bots(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.bot_timer().try_insert(BotTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(BOT_INTERVAL_MS).into()),
+    })?;
+    Ok(())
+}
+
+fn spawn_bot(ctx: &ReducerContext, arena_id: u32, managed: bool) -> Result<Bot, String> {
+    let mut player = ctx.db.player().try_insert(Player {
+        identity: Identity::from_byte_array(ctx.rng().gen()),
+        player_id: 0,
+        name: String::new(),
+        arena_id,
+    })?;
+    let bot = ctx.db.bot().try_insert(Bot {
+        bot_id: 0,
+        player_id: player.player_id,
+        managed,
+    })?;
+
+    player.name = format!("Bot {}", bot.bot_id);
+    log::info!("Spawned {} in arena {}", player.name, arena_id);
+    ctx.db.player().identity().update(player);
+    refresh_arena_player_count(ctx, arena_id);
+    Ok(bot)
+}
+
+fn despawn_bot(ctx: &ReducerContext, bot: &Bot) {
+    for circle in ctx.db.circle().player_id().filter(&bot.player_id) {
+        ctx.db.entity().entity_id().delete(&circle.entity_id);
+        ctx.db.circle().entity_id().delete(&circle.entity_id);
+    }
+    if let Some(player) = ctx.db.player().player_id().find(&bot.player_id) {
+        ctx.db.player().identity().delete(&player.identity);
+        ctx.db.player_stats().identity().delete(&player.identity);
+        refresh_arena_player_count(ctx, player.arena_id);
+    }
+    ctx.db.bot().bot_id().delete(&bot.bot_id);
+}
+
+fn bot_arena_id(ctx: &ReducerContext, bot: &Bot) -> Option<u32> {
+    ctx.db
+        .player()
+        .player_id()
+        .find(&bot.player_id)
+        .map(|player| player.arena_id)
+}
+
+pub fn managed_bots_in_arena(ctx: &ReducerContext, arena_id: u32) -> Vec<Bot> {
+    ctx.db
+        .bot()
+        .iter()
+        .filter(|bot| bot.managed && bot_arena_id(ctx, bot) == Some(arena_id))
+        .collect()
+}
+
+// A player just took a seat in `arena`. If that put it over its cap, managed
+// bots give up their seats.
+pub fn make_room_for_player(ctx: &ReducerContext, arena: &Arena) {
+    let surplus = players_in_arena(ctx, arena.arena_id).saturating_sub(arena.max_players);
+    for bot in managed_bots_in_arena(ctx, arena.arena_id)
+        .iter()
+        .take(surplus as usize)
+    {
+        despawn_bot(ctx, bot);
+    }
+}
+
+// Adds or removes managed bots so every arena has at least `min_population`
+// players, without going over its cap.
+fn maintain_population(ctx: &ReducerContext, min_population: u32) -> Result<(), String> {
+    for arena in ctx.db.arena().iter() {
+        let target = min_population.min(arena.max_players);
+        let population = players_in_arena(ctx, arena.arena_id);
+        if population < target {
+            for _ in population..target {
+                spawn_bot(ctx, arena.arena_id, true)?;
+            }
+        } else if population > target {
+            for bot in managed_bots_in_arena(ctx, arena.arena_id)
+                .iter()
+                .take((population - target) as usize)
+            {
+                despawn_bot(ctx, bot);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_bots(ctx: &ReducerContext, _timer: BotTimer) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    let config = get_config(ctx)?;
+    maintain_population(ctx, config.min_arena_population)?;
+
+    let mut bots_by_arena: HashMap<u32, Vec<u32>> = HashMap::new();
+    for bot in ctx.db.bot().iter() {
+        if let Some(arena_id) = bot_arena_id(ctx, &bot) {
+            bots_by_arena
+                .entry(arena_id)
+                .or_default()
+                .push(bot.player_id);
+        }
+    }
+
+    let rules = config.rules();
+    for (arena_id, player_ids) in bots_by_arena {
+        let world = load_world(ctx, &get_arena(ctx, arena_id)?, rules.clone());
+        for player_id in player_ids {
+            if !is_alive(ctx, player_id) {
+                spawn_player_initial_circle(ctx, arena_id, player_id)?;
+                continue;
+            }
+            if let Some(steering) = sim::steer_bot(&rules, player_id, &world.circles, &world.food) {
+                apply_player_input(ctx, player_id, steering.direction * steering.speed);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn add_bot(ctx: &ReducerContext, arena_id: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    let arena = get_arena(ctx, arena_id)?;
+    if !has_room(ctx, &arena) {
+        return Err(format!("Arena {} is full", arena.name));
+    }
+    spawn_bot(ctx, arena_id, false)?;
+    make_room_for_player(ctx, &arena);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_bot(ctx: &ReducerContext, bot_id: u32) -> Result<(), String> {
+    require_admin(ctx)?;
+    let bot = ctx.db.bot().bot_id().find(&bot_id).ok_or("Bot not found")?;
+    despawn_bot(ctx, &bot);
+    log::info!("Bot {} removed by {}", bot_id, ctx.sender);
+    Ok(())
+}
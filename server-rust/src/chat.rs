@@ -0,0 +1,105 @@
+use super::*;
+
+const MAX_CHAT_LENGTH: usize = 200;
+const CHAT_MESSAGE_TTL_MS: i64 = 5 * 60 * 1000;
+const CHAT_CLEANUP_INTERVAL_MS: u64 = 30_000;
+
+// Matched case-insensitively anywhere in a message and replaced with `*`s.
+const FILTERED_WORDS: &[&str] = &["fuck", "shit", "bitch", "cunt", "nigger", "faggot"];
+
+// Chat is scoped to the arena the sender was in.
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub message_id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub player_id: u32,
+    pub text: String,
+    pub sent_at: Timestamp,
+}
+
+#[spacetimedb::table(name = chat_cleanup_timer, scheduled(clean_chat_messages))]
+pub struct ChatCleanupTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub fn schedule_chat_cleanup(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.chat_cleanup_timer().try_insert(ChatCleanupTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(CHAT_CLEANUP_INTERVAL_MS).into()),
+    })?;
+    Ok(())
+}
+
+fn filter_words(text: &str) -> String {
+    let mut chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = text.chars().map(|c| c.to_ascii_lowercase()).collect();
+    for word in FILTERED_WORDS {
+        let word: Vec<char> = word.chars().collect();
+        let mut i = 0;
+        while i + word.len() <= lower.len() {
+            if lower[i..i + word.len()] == word[..] {
+                chars[i..i + word.len()].fill('*');
+                i += word.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[spacetimedb::reducer]
+pub fn send_chat(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+    if player.name.is_empty() {
+        return Err("Enter the game before chatting".into());
+    }
+
+    let text: String = text.trim().chars().filter(|c| !c.is_control()).collect();
+    if text.is_empty() {
+        return Err("Message must not be empty".into());
+    }
+    if text.chars().count() > MAX_CHAT_LENGTH {
+        return Err(format!(
+            "Message must be at most {MAX_CHAT_LENGTH} characters"
+        ));
+    }
+    if !try_consume_rate_limit_or_notify(ctx, RateLimitedAction::Chat) {
+        return Ok(());
+    }
+
+    ctx.db.chat_message().insert(ChatMessage {
+        message_id: 0,
+        arena_id: player.arena_id,
+        player_id: player.player_id,
+        text: filter_words(&text),
+        sent_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn clean_chat_messages(ctx: &ReducerContext, _timer: ChatCleanupTimer) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    let cutoff = ctx.timestamp.to_micros_since_unix_epoch() - CHAT_MESSAGE_TTL_MS * 1000;
+    for message in ctx.db.chat_message().iter() {
+        if message.sent_at.to_micros_since_unix_epoch() < cutoff {
+            ctx.db
+                .chat_message()
+                .message_id()
+                .delete(&message.message_id);
+        }
+    }
+    Ok(())
+}
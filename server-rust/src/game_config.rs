@@ -0,0 +1,157 @@
+use super::*;
+
+const MIN_TIMER_INTERVAL_MS: u64 = 10;
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            food_mass_min: 2,
+            food_mass_max: 4,
+            start_player_mass: sim::START_PLAYER_MASS,
+            start_player_speed: sim::START_PLAYER_SPEED,
+            minimum_safe_mass_ratio: sim::MINIMUM_SAFE_MASS_RATIO,
+            min_mass_to_split: sim::MIN_MASS_TO_SPLIT,
+            max_circles_per_player: sim::MAX_CIRCLES_PER_PLAYER as u32,
+            split_cooldown_ms: sim::SPLIT_COOLDOWN_MS,
+            split_recombine_delay_ms: (sim::SPLIT_RECOMBINE_DELAY_SEC * 1000.0) as u64,
+            spawn_food_interval_ms: 500,
+            move_all_players_interval_ms: 50,
+            min_arena_population: 0,
+        }
+    }
+}
+
+impl Config {
+    pub fn rules(&self) -> Rules {
+        Rules {
+            start_player_mass: self.start_player_mass,
+            start_player_speed: self.start_player_speed,
+            minimum_safe_mass_ratio: self.minimum_safe_mass_ratio,
+            min_mass_to_split: self.min_mass_to_split,
+            max_circles_per_player: self.max_circles_per_player as usize,
+            split_cooldown_ms: self.split_cooldown_ms,
+            split_recombine_delay_sec: self.split_recombine_delay_ms as f32 / 1000.0,
+            ..Rules::default()
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.food_mass_min == 0 || self.food_mass_min >= self.food_mass_max {
+            return Err("food_mass_min must be at least 1 and below food_mass_max".into());
+        }
+        if self.start_player_mass == 0 || self.start_player_speed == 0 {
+            return Err("start_player_mass and start_player_speed must be positive".into());
+        }
+        if !(self.minimum_safe_mass_ratio > 0.0 && self.minimum_safe_mass_ratio <= 1.0) {
+            return Err("minimum_safe_mass_ratio must be in (0, 1]".into());
+        }
+        if self.min_mass_to_split < 2 {
+            return Err("min_mass_to_split must be at least 2".into());
+        }
+        if self.max_circles_per_player == 0 {
+            return Err("max_circles_per_player must be at least 1".into());
+        }
+        if self.spawn_food_interval_ms < MIN_TIMER_INTERVAL_MS
+            || self.move_all_players_interval_ms < MIN_TIMER_INTERVAL_MS
+        {
+            return Err(format!(
+                "Timer intervals must be at least {MIN_TIMER_INTERVAL_MS} ms"
+            ));
+        }
+        Ok(())
+    }
+
+    // Spawns pick a point at least one radius away from every wall, so the
+    // largest player and food circles must fit inside the arena.
+    pub fn check_fits_world(&self, world_size: u64) -> Result<(), String> {
+        for (field, mass) in [
+            ("start_player_mass", self.start_player_mass),
+            ("food_mass_max", self.food_mass_max),
+        ] {
+            if mass_to_radius(mass) * 2.0 >= world_size as f32 {
+                return Err(format!(
+                    "{field} is too large for an arena of size {world_size}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn get_config(ctx: &ReducerContext) -> Result<Config, String> {
+    ctx.db
+        .config()
+        .id()
+        .find(0)
+        .ok_or_else(|| "Config not found".to_string())
+}
+
+pub fn schedule_spawn_food(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    interval_ms: u64,
+) -> Result<(), String> {
+    for timer in ctx.db.spawn_food_timer().iter() {
+        if timer.arena_id != arena_id {
+            continue;
+        }
+        ctx.db
+            .spawn_food_timer()
+            .scheduled_id()
+            .delete(&timer.scheduled_id);
+    }
+    ctx.db.spawn_food_timer().try_insert(SpawnFoodTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(interval_ms).into()),
+        arena_id,
+    })?;
+    Ok(())
+}
+
+pub fn schedule_move_all_players(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    interval_ms: u64,
+) -> Result<(), String> {
+    for timer in ctx.db.move_all_players_timer().iter() {
+        if timer.arena_id != arena_id {
+            continue;
+        }
+        ctx.db
+            .move_all_players_timer()
+            .scheduled_id()
+            .delete(&timer.scheduled_id);
+    }
+    ctx.db
+        .move_all_players_timer()
+        .try_insert(MoveAllPlayersTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(Duration::from_millis(interval_ms).into()),
+            arena_id,
+        })?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_config(ctx: &ReducerContext, config: Config) -> Result<(), String> {
+    require_admin(ctx)?;
+    let current = get_config(ctx)?;
+    config.validate()?;
+    for arena in ctx.db.arena().iter() {
+        config.check_fits_world(arena.world_size)?;
+    }
+
+    for arena in ctx.db.arena().iter() {
+        if config.spawn_food_interval_ms != current.spawn_food_interval_ms {
+            schedule_spawn_food(ctx, arena.arena_id, config.spawn_food_interval_ms)?;
+        }
+        if config.move_all_players_interval_ms != current.move_all_players_interval_ms {
+            schedule_move_all_players(ctx, arena.arena_id, config.move_all_players_interval_ms)?;
+        }
+    }
+
+    log::info!("Config updated by {}", ctx.sender);
+    ctx.db.config().id().update(Config { id: 0, ..config });
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use super::*;
+use std::collections::{BTreeMap, HashSet};
+
+const KILL_EVENT_TTL_MS: i64 = 10_000;
+const KILL_EVENT_CLEANUP_INTERVAL_MS: u64 = 5_000;
+
+// One row per player killed by another player, written when the victim's
+// last circle is eaten. `victim_mass` is the victim's total mass at death.
+// Clients react to inserts; rows are only kept around briefly so late
+// subscribers can still see them.
+#[spacetimedb::table(name = kill_event, public)]
+pub struct KillEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub event_id: u64,
+    #[index(btree)]
+    pub victim_player_id: u32,
+    pub killer_player_id: u32,
+    pub victim_mass: u32,
+    pub timestamp: Timestamp,
+}
+
+#[spacetimedb::table(name = kill_event_cleanup_timer, scheduled(clean_kill_events))]
+pub struct KillEventCleanupTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub fn schedule_kill_event_cleanup(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db
+        .kill_event_cleanup_timer()
+        .try_insert(KillEventCleanupTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(
+                Duration::from_millis(KILL_EVENT_CLEANUP_INTERVAL_MS).into(),
+            ),
+        })?;
+    Ok(())
+}
+
+// Emits an event for every player that died in one simulation step.
+// `circles` are the circles left in the arena after the step, as in
+// `record_step_stats`. All of a dead victim's circles were eaten during the
+// step, so their masses add up to the victim's mass at death. The kill is
+// credited to whoever ate the last one.
+pub fn emit_kill_events(
+    ctx: &ReducerContext,
+    collisions: &[sim::Collision],
+    circles: &[sim::Circle],
+) {
+    let alive: HashSet<u32> = circles.iter().map(|circle| circle.player_id).collect();
+
+    let mut deaths: BTreeMap<u32, (u32, u32)> = BTreeMap::new();
+    for collision in collisions {
+        if let sim::Collision::CircleEaten {
+            player_id,
+            victim_player_id,
+            mass,
+            ..
+        } = *collision
+        {
+            if alive.contains(&victim_player_id) {
+                continue;
+            }
+            let (killer_player_id, victim_mass) = deaths.entry(victim_player_id).or_default();
+            *killer_player_id = player_id;
+            *victim_mass += mass;
+        }
+    }
+
+    for (victim_player_id, (killer_player_id, victim_mass)) in deaths {
+        ctx.db.kill_event().insert(KillEvent {
+            event_id: 0,
+            victim_player_id,
+            killer_player_id,
+            victim_mass,
+            timestamp: ctx.timestamp,
+        });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn clean_kill_events(
+    ctx: &ReducerContext,
+    _timer: KillEventCleanupTimer,
+) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    let cutoff = ctx.timestamp.to_micros_since_unix_epoch() - KILL_EVENT_TTL_MS * 1000;
+    for event in ctx.db.kill_event().iter() {
+        if event.timestamp.to_micros_since_unix_epoch() < cutoff {
+            ctx.db.kill_event().event_id().delete(&event.event_id);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use super::*;
+use std::collections::HashMap;
+
+const LEADERBOARD_SIZE: usize = 10;
+const LEADERBOARD_INTERVAL_MS: u64 = 1000;
+
+// The top players of each arena by total mass, rebuilt by `update_leaderboard`.
+// `rank` starts at 1 within each arena.
+#[spacetimedb::table(name = leaderboard, public)]
+pub struct LeaderboardEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub entry_id: u64,
+    #[index(btree)]
+    pub arena_id: u32,
+    pub rank: u32,
+    pub player_id: u32,
+    pub name: String,
+    pub total_mass: u32,
+}
+
+#[spacetimedb::table(name = leaderboard_timer, scheduled(update_leaderboard))]
+pub struct LeaderboardTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub fn schedule_leaderboard(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.leaderboard_timer().try_insert(LeaderboardTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(Duration::from_millis(LEADERBOARD_INTERVAL_MS).into()),
+    })?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_leaderboard(ctx: &ReducerContext, _timer: LeaderboardTimer) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    for entry in ctx.db.leaderboard().iter() {
+        ctx.db.leaderboard().entry_id().delete(&entry.entry_id);
+    }
+
+    for arena in ctx.db.arena().iter() {
+        let mut total_mass: HashMap<u32, u32> = HashMap::new();
+        for entity in ctx.db.entity().arena_id().filter(&arena.arena_id) {
+            if let Some(circle) = ctx.db.circle().entity_id().find(&entity.entity_id) {
+                *total_mass.entry(circle.player_id).or_default() += entity.mass;
+            }
+        }
+
+        let mut ranking: Vec<(u32, u32)> = total_mass.into_iter().collect();
+        // Heaviest first, ties broken by who joined first
+        ranking.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let players = ranking.into_iter().filter_map(|(player_id, total_mass)| {
+            let player = ctx.db.player().player_id().find(&player_id)?;
+            Some((player.name, player_id, total_mass))
+        });
+        for (i, (name, player_id, total_mass)) in players.take(LEADERBOARD_SIZE).enumerate() {
+            ctx.db.leaderboard().insert(LeaderboardEntry {
+                entry_id: 0,
+                arena_id: arena.arena_id,
+                rank: i as u32 + 1,
+                player_id,
+                name,
+                total_mass,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,31 @@
+mod admin;
+mod arena;
+mod bot;
+mod chat;
+mod game_config;
+mod kill_event;
+mod leaderboard;
 mod math;
-
+mod player_stats;
+mod rate_limit;
+
+use admin::*;
+use arena::*;
+use bot::*;
+use chat::*;
+use game_config::*;
+use kill_event::*;
+use leaderboard::*;
 use math::*;
+use player_stats::*;
+use rate_limit::*;
 
+use blackholio_sim::{self as sim, mass_to_radius, Rules, Vector2, World};
 use log::{debug, info};
 use spacetimedb::{
     rand::Rng, Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp,
 };
+use std::collections::HashSet;
 use std::time::Duration;
 
 #[spacetimedb::table(name = spawn_food_timer, scheduled(spawn_food))]
@@ -14,15 +34,28 @@ pub struct SpawnFoodTimer {
     #[auto_inc]
     scheduled_id: u64,
     scheduled_at: spacetimedb::ScheduleAt,
+    arena_id: u32,
 }
 
 // We're using this table as a singleton, so in this table
 // there only be one element where the `id` is 0.
+// These rules are shared by every arena and can be tuned at runtime with `update_config`.
 #[spacetimedb::table(name = config, public)]
 pub struct Config {
     #[primary_key]
     pub id: u32,
-    pub world_size: u64,
+    pub food_mass_min: u32,
+    pub food_mass_max: u32,
+    pub start_player_mass: u32,
+    pub start_player_speed: u32,
+    pub minimum_safe_mass_ratio: f32,
+    pub min_mass_to_split: u32,
+    pub max_circles_per_player: u32,
+    pub split_cooldown_ms: u64,
+    pub split_recombine_delay_ms: u64,
+    pub spawn_food_interval_ms: u64,
+    pub move_all_players_interval_ms: u64,
+    pub min_arena_population: u32,
 }
 
 #[spacetimedb::table(name = entity, public)]
@@ -33,6 +66,8 @@ pub struct Entity {
     #[auto_inc]
     #[primary_key]
     pub entity_id: u32,
+    #[index(btree)]
+    pub arena_id: u32,
     pub position: DbVector2,
     pub mass: u32,
 }
@@ -46,6 +81,8 @@ pub struct Circle {
     pub direction: DbVector2,
     pub speed: f32,
     pub last_split_time: Timestamp,
+    // Launch speed of a freshly split circle, decayed every tick
+    pub impulse: DbVector2,
 }
 
 #[spacetimedb::table(name = food, public)]
@@ -64,6 +101,8 @@ pub struct Player {
     #[auto_inc]
     player_id: u32,
     name: String,
+    #[index(btree)]
+    arena_id: u32,
 }
 
 // Note the `init` parameter passed to the reducer macro.
@@ -71,33 +110,38 @@ pub struct Player {
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("Initializing...");
-    ctx.db.config().try_insert(Config {
-        id: 0,
-        world_size: 1000,
-    })?;
-    ctx.db.spawn_food_timer().try_insert(SpawnFoodTimer {
-        scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(500).into()),
-    })?;
-    ctx.db
-        .move_all_players_timer()
-        .try_insert(MoveAllPlayersTimer {
-            scheduled_id: 0,
-            scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
-        })?;
+    seed_admins(ctx)?;
+    ctx.db.config().try_insert(Config::default())?;
+    create_default_arena(ctx)?;
+    schedule_leaderboard(ctx)?;
+    schedule_kill_event_cleanup(ctx)?;
+    schedule_bots(ctx)?;
+    schedule_chat_cleanup(ctx)?;
     Ok(())
 }
 
 #[spacetimedb::reducer(client_connected)]
 pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
+    if is_banned(ctx, ctx.sender) {
+        return Err("This identity is banned".into());
+    }
+
     // Check if the player was previously logged out
-    if let Some(player) = ctx.db.logged_out_player().identity().find(&ctx.sender) {
-        ctx.db.player().insert(player.clone());
+    if let Some(mut player) = ctx.db.logged_out_player().identity().find(&ctx.sender) {
         ctx.db
             .logged_out_player()
             .identity()
             .delete(&player.identity);
 
+        // Move them elsewhere if their arena filled up while they were away
+        let arena = ctx.db.arena().arena_id().find(&player.arena_id);
+        if !arena.is_some_and(|arena| has_room(ctx, &arena)) {
+            player.arena_id = find_open_arena(ctx)?;
+        }
+        ctx.db.player().insert(player.clone());
+        make_room_for_player(ctx, &get_arena(ctx, player.arena_id)?);
+        refresh_arena_player_count(ctx, player.arena_id);
+
         // Log connection with existing player
         let str = if player.name.is_empty() {
             ctx.sender.to_string()
@@ -107,11 +151,14 @@ pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
         log::info!("Player reconnected: {}", str);
     } else {
         // Create a new player with empty name
-        ctx.db.player().try_insert(Player {
+        let player = ctx.db.player().try_insert(Player {
             identity: ctx.sender,
             player_id: 0,
             name: String::new(),
+            arena_id: find_open_arena(ctx)?,
         })?;
+        make_room_for_player(ctx, &get_arena(ctx, player.arena_id)?);
+        refresh_arena_player_count(ctx, player.arena_id);
 
         log::info!("New player connected with identity: {:?}", ctx.sender);
     }
@@ -128,8 +175,11 @@ pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
         .find(&ctx.sender)
         .ok_or("Player not found")?;
     let player_id = player.player_id;
+    let arena_id = player.arena_id;
     ctx.db.logged_out_player().insert(player);
     ctx.db.player().identity().delete(&ctx.sender);
+    end_player_session(ctx, ctx.sender);
+    refresh_arena_player_count(ctx, arena_id);
 
     // Remove any circles from the arena
     for circle in ctx.db.circle().player_id().filter(&player_id) {
@@ -142,42 +192,40 @@ pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
 
 #[spacetimedb::reducer]
 pub fn debug(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
     log::debug!("This reducer was called by {}.", ctx.sender);
     Ok(())
 }
 
-const FOOD_MASS_MIN: u32 = 2;
-const FOOD_MASS_MAX: u32 = 4;
-const TARGET_FOOD_COUNT: usize = 600;
-
-fn mass_to_radius(mass: u32) -> f32 {
-    (mass as f32).sqrt()
-}
-
 #[spacetimedb::reducer]
-pub fn spawn_food(ctx: &ReducerContext, _timer: SpawnFoodTimer) -> Result<(), String> {
-    if ctx.db.player().count() == 0 {
+pub fn spawn_food(ctx: &ReducerContext, timer: SpawnFoodTimer) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    let arena_id = timer.arena_id;
+    if players_in_arena(ctx, arena_id) == 0 {
         // Are there no logged in players? Skip food spawn.
         return Ok(());
     }
 
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
+    let config = get_config(ctx)?;
+    let arena = get_arena(ctx, arena_id)?;
+    let world_size = arena.world_size;
 
     let mut rng = ctx.rng();
-    let mut food_count = ctx.db.food().count();
-    while food_count < TARGET_FOOD_COUNT as u64 {
-        let food_mass = rng.gen_range(FOOD_MASS_MIN..FOOD_MASS_MAX);
+    let mut food_count = ctx
+        .db
+        .entity()
+        .arena_id()
+        .filter(&arena_id)
+        .filter(|entity| ctx.db.food().entity_id().find(&entity.entity_id).is_some())
+        .count();
+    while food_count < arena.target_food_count as usize {
+        let food_mass = rng.gen_range(config.food_mass_min..config.food_mass_max);
         let food_radius = mass_to_radius(food_mass);
         let x = rng.gen_range(food_radius..world_size as f32 - food_radius);
         let y = rng.gen_range(food_radius..world_size as f32 - food_radius);
         let entity = ctx.db.entity().try_insert(Entity {
             entity_id: 0,
+            arena_id,
             position: DbVector2 { x, y },
             mass: food_mass,
         })?;
@@ -191,12 +239,46 @@ pub fn spawn_food(ctx: &ReducerContext, _timer: SpawnFoodTimer) -> Result<(), St
     Ok(())
 }
 
-const START_PLAYER_MASS: u32 = 15;
+const MAX_NAME_LENGTH: usize = 16;
+
+// Trims the name and checks it is 1 to `MAX_NAME_LENGTH` letters, digits,
+// spaces, `_` or `-`, and not taken by another online player.
+fn validate_name(ctx: &ReducerContext, player_id: u32, name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Name must not be empty".into());
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("Name must be at most {MAX_NAME_LENGTH} characters"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == ' ' || c == '_' || c == '-')
+    {
+        return Err("Name may only contain letters, digits, spaces, '_' and '-'".into());
+    }
+    if ctx
+        .db
+        .player()
+        .iter()
+        .any(|p| p.player_id != player_id && p.name.eq_ignore_ascii_case(name))
+    {
+        return Err(format!("The name {name} is already taken"));
+    }
+    Ok(name.to_string())
+}
+
+fn is_alive(ctx: &ReducerContext, player_id: u32) -> bool {
+    ctx.db
+        .circle()
+        .player_id()
+        .filter(&player_id)
+        .next()
+        .is_some()
+}
 
 #[spacetimedb::reducer]
 pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
-    log::info!("Creating player with name {}", name);
-
     // Get the player
     let mut player: Player = ctx
         .db
@@ -205,33 +287,64 @@ pub fn enter_game(ctx: &ReducerContext, name: String) -> Result<(), String> {
         .find(ctx.sender)
         .ok_or("Player not found")?;
     let player_id = player.player_id;
+    let arena_id = player.arena_id;
+    if is_alive(ctx, player_id) {
+        return Err("You are already in the game".into());
+    }
+
+    let name = validate_name(ctx, player_id, &name)?;
+    log::info!("Creating player with name {}", name);
 
     // Update player name
-    player.name = name.clone();
+    player.name = name;
     ctx.db.player().identity().update(player);
 
     // Spawn the player's initial circle
-    spawn_player_initial_circle(ctx, player_id)?;
+    let entity = spawn_player_initial_circle(ctx, arena_id, player_id)?;
+    start_player_session(ctx, ctx.sender, entity.mass);
 
     Ok(())
 }
 
-fn spawn_player_initial_circle(ctx: &ReducerContext, player_id: u32) -> Result<Entity, String> {
-    let mut rng = ctx.rng();
-    let world_size = ctx
+#[spacetimedb::reducer]
+pub fn respawn(ctx: &ReducerContext) -> Result<(), String> {
+    if !try_consume_rate_limit_or_notify(ctx, RateLimitedAction::Respawn) {
+        return Ok(());
+    }
+    let player = ctx
         .db
-        .config()
-        .id()
-        .find(&0)
-        .ok_or("Config not found")?
-        .world_size;
-    let player_start_radius = mass_to_radius(START_PLAYER_MASS);
+        .player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+    if is_alive(ctx, player.player_id) {
+        return Err("Cannot respawn while you still have circles".into());
+    }
+
+    let entity = spawn_player_initial_circle(ctx, player.arena_id, player.player_id)?;
+    start_player_session(ctx, ctx.sender, entity.mass);
+    log::info!("Player {} respawned", player.name);
+
+    Ok(())
+}
+
+fn spawn_player_initial_circle(
+    ctx: &ReducerContext,
+    arena_id: u32,
+    player_id: u32,
+) -> Result<Entity, String> {
+    let mut rng = ctx.rng();
+    let config = get_config(ctx)?;
+    let world_size = get_arena(ctx, arena_id)?.world_size;
+    let start_player_mass = config.start_player_mass;
+    let player_start_radius = mass_to_radius(start_player_mass);
     let x = rng.gen_range(player_start_radius..(world_size as f32 - player_start_radius));
     let y = rng.gen_range(player_start_radius..(world_size as f32 - player_start_radius));
     spawn_circle_at(
         ctx,
+        arena_id,
         player_id,
-        START_PLAYER_MASS,
+        start_player_mass,
         DbVector2 { x, y },
         ctx.timestamp,
     )
@@ -239,6 +352,7 @@ fn spawn_player_initial_circle(ctx: &ReducerContext, player_id: u32) -> Result<E
 
 fn spawn_circle_at(
     ctx: &ReducerContext,
+    arena_id: u32,
     player_id: u32,
     mass: u32,
     position: DbVector2,
@@ -246,6 +360,7 @@ fn spawn_circle_at(
 ) -> Result<Entity, String> {
     let entity = ctx.db.entity().try_insert(Entity {
         entity_id: 0,
+        arena_id,
         position,
         mass,
     })?;
@@ -256,23 +371,92 @@ fn spawn_circle_at(
         direction: DbVector2 { x: 0.0, y: 1.0 },
         speed: 0.0,
         last_split_time: timestamp,
+        impulse: DbVector2 { x: 0.0, y: 0.0 },
     })?;
     Ok(entity)
 }
 
 #[spacetimedb::reducer]
 pub fn update_player_input(ctx: &ReducerContext, direction: DbVector2) -> Result<(), String> {
+    if !try_consume_rate_limit(ctx, RateLimitedAction::PlayerInput) {
+        return Ok(());
+    }
     let player = ctx
         .db
         .player()
         .identity()
         .find(&ctx.sender)
         .ok_or("Player not found")?;
-    for mut circle in ctx.db.circle().player_id().filter(&player.player_id) {
-        circle.direction = direction.normalized();
+    apply_player_input(ctx, player.player_id, direction.into());
+    Ok(())
+}
+
+fn apply_player_input(ctx: &ReducerContext, player_id: u32, direction: Vector2) {
+    for mut circle in ctx.db.circle().player_id().filter(&player_id) {
+        circle.direction = direction.normalized().into();
         circle.speed = direction.magnitude().clamp(0.0, 1.0);
         ctx.db.circle().entity_id().update(circle);
     }
+}
+
+#[spacetimedb::reducer]
+pub fn player_split(ctx: &ReducerContext) -> Result<(), String> {
+    if !try_consume_rate_limit_or_notify(ctx, RateLimitedAction::PlayerSplit) {
+        return Ok(());
+    }
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(&ctx.sender)
+        .ok_or("Player not found")?;
+    let rules = get_config(ctx)?.rules();
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let circles: Vec<Circle> = ctx
+        .db
+        .circle()
+        .player_id()
+        .filter(&player.player_id)
+        .collect();
+    let mut circle_count = circles.len();
+
+    for mut circle in circles {
+        if circle_count >= rules.max_circles_per_player {
+            break;
+        }
+
+        let Some(mut circle_entity) = ctx.db.entity().entity_id().find(&circle.entity_id) else {
+            continue;
+        };
+        let world_size = get_arena(ctx, circle_entity.arena_id)?.world_size as f32;
+        let sim_circle = to_sim_circle(&circle, &circle_entity);
+        let Some(split) = rules.split(&sim_circle, now, world_size) else {
+            continue;
+        };
+
+        // Launch the new half along the circle's movement direction
+        let split_entity = spawn_circle_at(
+            ctx,
+            circle_entity.arena_id,
+            player.player_id,
+            split.split_mass,
+            split.position.into(),
+            ctx.timestamp,
+        )?;
+        if let Some(mut split_circle) = ctx.db.circle().entity_id().find(&split_entity.entity_id) {
+            split_circle.direction = circle.direction;
+            split_circle.speed = circle.speed;
+            split_circle.impulse = split.impulse.into();
+            ctx.db.circle().entity_id().update(split_circle);
+        }
+
+        circle_entity.mass = split.remaining_mass;
+        circle.last_split_time = ctx.timestamp;
+        ctx.db.circle().entity_id().update(circle);
+        ctx.db.entity().entity_id().update(circle_entity);
+        circle_count += 1;
+    }
+
     Ok(())
 }
 
@@ -282,86 +466,94 @@ pub struct MoveAllPlayersTimer {
     #[auto_inc]
     scheduled_id: u64,
     scheduled_at: spacetimedb::ScheduleAt,
+    arena_id: u32,
 }
 
-const START_PLAYER_SPEED: u32 = 10;
+fn to_sim_entity(entity: &Entity) -> sim::Entity {
+    sim::Entity {
+        entity_id: entity.entity_id,
+        position: entity.position.into(),
+        mass: entity.mass,
+    }
+}
 
-fn mass_to_max_move_speed(mass: u32) -> f32 {
-    2.0 * START_PLAYER_SPEED as f32 / (1.0 + (mass as f32 / START_PLAYER_MASS as f32).sqrt())
+fn to_sim_circle(circle: &Circle, entity: &Entity) -> sim::Circle {
+    sim::Circle {
+        entity: to_sim_entity(entity),
+        player_id: circle.player_id,
+        direction: circle.direction.into(),
+        speed: circle.speed,
+        last_split_micros: circle.last_split_time.to_micros_since_unix_epoch(),
+        impulse: circle.impulse.into(),
+    }
 }
-const MINIMUM_SAFE_MASS_RATIO: f32 = 0.85;
-
-fn is_overlapping(a: &Entity, b: &Entity) -> bool {
-    let dx = a.position.x - b.position.x;
-    let dy = a.position.y - b.position.y;
-    let distance_sq = dx * dx + dy * dy;
-
-    let radius_a = mass_to_radius(a.mass);
-    let radius_b = mass_to_radius(b.mass);
-
-    // If the distance between the two circle centers is less than the
-    // maximum radius, then the center of the smaller circle is inside
-    // the larger circle. This gives some leeway for the circles to overlap
-    // before being eaten.
-    let max_radius = f32::max(radius_a, radius_b);
-    distance_sq <= max_radius * max_radius
+
+fn load_world(ctx: &ReducerContext, arena: &Arena, rules: Rules) -> World {
+    let mut world = World::new(arena.world_size as f32, rules);
+    for entity in ctx.db.entity().arena_id().filter(&arena.arena_id) {
+        if let Some(circle) = ctx.db.circle().entity_id().find(&entity.entity_id) {
+            world.circles.push(to_sim_circle(&circle, &entity));
+        } else if ctx.db.food().entity_id().find(&entity.entity_id).is_some() {
+            world.food.push(to_sim_entity(&entity));
+        }
+    }
+    world
 }
 
 #[spacetimedb::reducer]
-pub fn move_all_players(ctx: &ReducerContext, _timer: MoveAllPlayersTimer) -> Result<(), String> {
-    let world_size = ctx
-        .db
-        .config()
-        .id()
-        .find(0)
-        .ok_or("Config not found")?
-        .world_size;
-
-    // Handle player input
-    for circle in ctx.db.circle().iter() {
-        let circle_entity = ctx.db.entity().entity_id().find(&circle.entity_id);
-        if !circle_entity.is_some() {
-            // This can happen if a circle is eaten by another circle
-            continue;
-        }
-        let mut circle_entity = circle_entity.unwrap();
-        let circle_radius = mass_to_radius(circle_entity.mass);
-        let direction = circle.direction * circle.speed;
-        let new_pos =
-            circle_entity.position + direction * mass_to_max_move_speed(circle_entity.mass);
-        let min = circle_radius;
-        let max = world_size as f32 - circle_radius;
-        circle_entity.position.x = new_pos.x.clamp(min, max);
-        circle_entity.position.y = new_pos.y.clamp(min, max);
-
-        // Check collisions
-        for entity in ctx.db.entity().iter() {
-            if entity.entity_id == circle_entity.entity_id {
-                continue;
+pub fn move_all_players(ctx: &ReducerContext, timer: MoveAllPlayersTimer) -> Result<(), String> {
+    require_scheduler(ctx)?;
+    let arena_id = timer.arena_id;
+    let config = get_config(ctx)?;
+    let arena = get_arena(ctx, arena_id)?;
+    let mut world = load_world(ctx, &arena, config.rules());
+    advance_world_tick(ctx, arena_id);
+    if world.circles.is_empty() {
+        return Ok(());
+    }
+
+    // Circles whose launch impulse changes this step and has to be written back
+    let launched: HashSet<u32> = world
+        .circles
+        .iter()
+        .filter(|circle| circle.impulse != Vector2::default())
+        .map(|circle| circle.entity.entity_id)
+        .collect();
+
+    let collisions = world.step(ctx.timestamp.to_micros_since_unix_epoch());
+    record_step_stats(ctx, &collisions, &world.circles);
+    emit_kill_events(ctx, &collisions, &world.circles);
+
+    for collision in collisions {
+        match collision {
+            sim::Collision::FoodEaten { food_id, .. } => {
+                ctx.db.entity().entity_id().delete(&food_id);
+                ctx.db.food().entity_id().delete(&food_id);
             }
-            if is_overlapping(&circle_entity, &entity) {
-                // Check to see if we're overlapping with food
-                if ctx.db.food().entity_id().find(&entity.entity_id).is_some() {
-                    ctx.db.entity().entity_id().delete(&entity.entity_id);
-                    ctx.db.food().entity_id().delete(&entity.entity_id);
-                    circle_entity.mass += entity.mass;
-                }
-
-                // Check to see if we're overlapping with another circle owned by another player
-                let other_circle = ctx.db.circle().entity_id().find(&entity.entity_id);
-                if let Some(other_circle) = other_circle {
-                    if other_circle.player_id != circle.player_id {
-                        let mass_ratio = entity.mass as f32 / circle_entity.mass as f32;
-                        if mass_ratio < MINIMUM_SAFE_MASS_RATIO {
-                            ctx.db.entity().entity_id().delete(&entity.entity_id);
-                            ctx.db.circle().entity_id().delete(&entity.entity_id);
-                            circle_entity.mass += entity.mass;
-                        }
-                    }
-                }
+            sim::Collision::CircleEaten { victim_id, .. } => {
+                ctx.db.entity().entity_id().delete(&victim_id);
+                ctx.db.circle().entity_id().delete(&victim_id);
+            }
+            sim::Collision::Recombined { absorbed_id, .. } => {
+                ctx.db.entity().entity_id().delete(&absorbed_id);
+                ctx.db.circle().entity_id().delete(&absorbed_id);
             }
         }
-        ctx.db.entity().entity_id().update(circle_entity);
+    }
+
+    for circle in world.circles {
+        if launched.contains(&circle.entity.entity_id) {
+            if let Some(mut row) = ctx.db.circle().entity_id().find(&circle.entity.entity_id) {
+                row.impulse = circle.impulse.into();
+                ctx.db.circle().entity_id().update(row);
+            }
+        }
+        ctx.db.entity().entity_id().update(Entity {
+            entity_id: circle.entity.entity_id,
+            arena_id,
+            position: circle.entity.position.into(),
+            mass: circle.entity.mass,
+        });
     }
 
     Ok(())
@@ -0,0 +1,150 @@
+use super::*;
+use spacetimedb::{client_visibility_filter, Filter};
+use std::collections::{HashMap, HashSet};
+
+// Lifetime statistics of a player, kept across sessions.
+// A session runs from `enter_game` until the player dies or disconnects.
+#[spacetimedb::table(name = player_stats, public)]
+pub struct PlayerStats {
+    #[primary_key]
+    pub identity: Identity,
+    pub total_play_time_ms: u64,
+    pub games_played: u32,
+    pub highest_mass: u32,
+    pub food_eaten: u32,
+    pub players_eaten: u32,
+    pub deaths: u32,
+    pub session_started_at: Option<Timestamp>,
+}
+
+// Each client only gets to see its own stats. 1.3 has no views and reducers
+// cannot return rows, so this needs the `unstable` feature of spacetimedb.
+#[client_visibility_filter]
+const PLAYER_STATS_FILTER: Filter =
+    Filter::Sql("SELECT * FROM player_stats WHERE identity = :sender");
+
+#[derive(Default)]
+struct StatsDelta {
+    food_eaten: u32,
+    players_eaten: u32,
+    died: bool,
+}
+
+fn find_stats(ctx: &ReducerContext, identity: Identity) -> PlayerStats {
+    ctx.db
+        .player_stats()
+        .identity()
+        .find(&identity)
+        .unwrap_or(PlayerStats {
+            identity,
+            total_play_time_ms: 0,
+            games_played: 0,
+            highest_mass: 0,
+            food_eaten: 0,
+            players_eaten: 0,
+            deaths: 0,
+            session_started_at: None,
+        })
+}
+
+fn save_stats(ctx: &ReducerContext, stats: PlayerStats) {
+    if ctx
+        .db
+        .player_stats()
+        .identity()
+        .find(&stats.identity)
+        .is_some()
+    {
+        ctx.db.player_stats().identity().update(stats);
+    } else {
+        ctx.db.player_stats().insert(stats);
+    }
+}
+
+fn end_session(ctx: &ReducerContext, stats: &mut PlayerStats) {
+    if let Some(started_at) = stats.session_started_at.take() {
+        let elapsed_micros =
+            ctx.timestamp.to_micros_since_unix_epoch() - started_at.to_micros_since_unix_epoch();
+        stats.total_play_time_ms += elapsed_micros.max(0) as u64 / 1000;
+    }
+}
+
+pub fn start_player_session(ctx: &ReducerContext, identity: Identity, mass: u32) {
+    let mut stats = find_stats(ctx, identity);
+    end_session(ctx, &mut stats);
+    stats.games_played += 1;
+    stats.highest_mass = stats.highest_mass.max(mass);
+    stats.session_started_at = Some(ctx.timestamp);
+    save_stats(ctx, stats);
+}
+
+pub fn end_player_session(ctx: &ReducerContext, identity: Identity) {
+    let mut stats = find_stats(ctx, identity);
+    if stats.session_started_at.is_some() {
+        end_session(ctx, &mut stats);
+        save_stats(ctx, stats);
+    }
+}
+
+// Folds the outcome of one simulation step into the stats of the players involved.
+// `circles` are the circles left in the arena after the step.
+pub fn record_step_stats(
+    ctx: &ReducerContext,
+    collisions: &[sim::Collision],
+    circles: &[sim::Circle],
+) {
+    let mut total_mass: HashMap<u32, u32> = HashMap::new();
+    for circle in circles {
+        *total_mass.entry(circle.player_id).or_default() += circle.entity.mass;
+    }
+
+    let mut deltas: HashMap<u32, StatsDelta> = HashMap::new();
+    let mut dead = HashSet::new();
+    for collision in collisions {
+        match *collision {
+            sim::Collision::FoodEaten { player_id, .. } => {
+                deltas.entry(player_id).or_default().food_eaten += 1;
+            }
+            sim::Collision::CircleEaten {
+                player_id,
+                victim_player_id,
+                ..
+            } => {
+                // The victim is dead once their last circle is gone
+                if !total_mass.contains_key(&victim_player_id) && dead.insert(victim_player_id) {
+                    deltas.entry(player_id).or_default().players_eaten += 1;
+                    deltas.entry(victim_player_id).or_default().died = true;
+                }
+            }
+            sim::Collision::Recombined { .. } => {}
+        }
+    }
+
+    let player_ids: HashSet<u32> = total_mass.keys().chain(deltas.keys()).copied().collect();
+    for player_id in player_ids {
+        let Some(player) = ctx.db.player().player_id().find(&player_id) else {
+            continue;
+        };
+        let mut stats = find_stats(ctx, player.identity);
+        let mut changed = false;
+
+        let mass = total_mass.get(&player_id).copied().unwrap_or(0);
+        if mass > stats.highest_mass {
+            stats.highest_mass = mass;
+            changed = true;
+        }
+        if let Some(delta) = deltas.get(&player_id) {
+            stats.food_eaten += delta.food_eaten;
+            stats.players_eaten += delta.players_eaten;
+            if delta.died {
+                stats.deaths += 1;
+                end_session(ctx, &mut stats);
+            }
+            changed = true;
+        }
+
+        if changed {
+            save_stats(ctx, stats);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+use spacetimedb::{client_visibility_filter, Filter};
+
+// Reducers that are rate limited per identity, each with its own bucket.
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitedAction {
+    PlayerInput,
+    PlayerSplit,
+    Respawn,
+    Chat,
+}
+
+impl RateLimitedAction {
+    // (burst capacity, tokens refilled per second)
+    fn limit(self) -> (f32, f32) {
+        match self {
+            // The client sends 20 updates per second
+            RateLimitedAction::PlayerInput => (30.0, 25.0),
+            RateLimitedAction::PlayerSplit => (5.0, 4.0),
+            RateLimitedAction::Respawn => (2.0, 0.5),
+            RateLimitedAction::Chat => (3.0, 0.5),
+        }
+    }
+}
+
+// A token bucket per identity and action. `rejected_count` is kept
+// around across sessions for spotting abusive clients.
+#[spacetimedb::table(name = rate_limit_bucket)]
+pub struct RateLimitBucket {
+    #[primary_key]
+    #[auto_inc]
+    bucket_id: u64,
+    #[index(btree)]
+    identity: Identity,
+    action: RateLimitedAction,
+    tokens: f32,
+    last_refill: Timestamp,
+    rejected_count: u64,
+}
+
+// The last manual action of each identity that was rate limited, so the client
+// can tell the player. Throttled reducers cannot report this by failing, see
+// `try_consume_rate_limit_or_notify`.
+#[spacetimedb::table(name = rate_limit_notice, public)]
+pub struct RateLimitNotice {
+    #[primary_key]
+    identity: Identity,
+    action: RateLimitedAction,
+    rejected_at: Timestamp,
+}
+
+// Each client only gets to see its own notice
+#[client_visibility_filter]
+const RATE_LIMIT_NOTICE_FILTER: Filter =
+    Filter::Sql("SELECT * FROM rate_limit_notice WHERE identity = :sender");
+
+// Takes a token from the sender's bucket for `action`. Returns false if the
+// bucket is empty, in which case the caller should drop the request with
+// `Ok(())`: returning an error would roll back the rejection count.
+// Use this for requests the client sends continuously, like input.
+pub fn try_consume_rate_limit(ctx: &ReducerContext, action: RateLimitedAction) -> bool {
+    let (capacity, refill_per_sec) = action.limit();
+    let Some(mut bucket) = ctx
+        .db
+        .rate_limit_bucket()
+        .identity()
+        .filter(&ctx.sender)
+        .find(|bucket| bucket.action == action)
+    else {
+        ctx.db.rate_limit_bucket().insert(RateLimitBucket {
+            bucket_id: 0,
+            identity: ctx.sender,
+            action,
+            tokens: capacity - 1.0,
+            last_refill: ctx.timestamp,
+            rejected_count: 0,
+        });
+        return true;
+    };
+
+    let elapsed_sec = (ctx.timestamp.to_micros_since_unix_epoch()
+        - bucket.last_refill.to_micros_since_unix_epoch()) as f32
+        / 1_000_000.0;
+    bucket.tokens = (bucket.tokens + elapsed_sec.max(0.0) * refill_per_sec).min(capacity);
+    bucket.last_refill = ctx.timestamp;
+
+    let allowed = bucket.tokens >= 1.0;
+    if allowed {
+        bucket.tokens -= 1.0;
+    } else {
+        bucket.rejected_count += 1;
+        log::debug!(
+            "Rate limited {:?} from {} ({} rejected)",
+            action,
+            ctx.sender,
+            bucket.rejected_count
+        );
+    }
+    ctx.db.rate_limit_bucket().bucket_id().update(bucket);
+    allowed
+}
+
+// Like `try_consume_rate_limit`, for actions the player triggers by hand.
+// A throttled request also writes the sender's `rate_limit_notice` row so the
+// client can tell the player. The caller must still drop it with `Ok(())`:
+// the rejection count and the notice only persist if the reducer commits.
+pub fn try_consume_rate_limit_or_notify(ctx: &ReducerContext, action: RateLimitedAction) -> bool {
+    if try_consume_rate_limit(ctx, action) {
+        return true;
+    }
+
+    let notice = RateLimitNotice {
+        identity: ctx.sender,
+        action,
+        rejected_at: ctx.timestamp,
+    };
+    if ctx.db.rate_limit_notice().identity().find(&ctx.sender).is_some() {
+        ctx.db.rate_limit_notice().identity().update(notice);
+    } else {
+        ctx.db.rate_limit_notice().insert(notice);
+    }
+    false
+}